@@ -1,10 +1,41 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ProjectKind {
+    App,
+    Cli,
+    Library,
+    Docs,
+}
+
+impl fmt::Display for ProjectKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProjectKind::App => write!(f, "App"),
+            ProjectKind::Cli => write!(f, "CLI tool"),
+            ProjectKind::Library => write!(f, "Library"),
+            ProjectKind::Docs => write!(f, "Documentation site"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Framework {
     NextJs,
     TanStackStart,
+    ViteReact,
+    Hono,
+    Express,
+    Fastify,
+    SolidStart,
+    Expo,
+    BrowserExtension,
+    Tauri,
+    Fumadocs,
+    Starlight,
+    Cli,
+    Library,
 }
 
 impl fmt::Display for Framework {
@@ -12,14 +43,269 @@ impl fmt::Display for Framework {
         match self {
             Framework::NextJs => write!(f, "Next.js"),
             Framework::TanStackStart => write!(f, "TanStack Start"),
+            Framework::ViteReact => write!(f, "Vite + React"),
+            Framework::Hono => write!(f, "Hono"),
+            Framework::Express => write!(f, "Express"),
+            Framework::Fastify => write!(f, "Fastify"),
+            Framework::SolidStart => write!(f, "SolidStart"),
+            Framework::Expo => write!(f, "Expo"),
+            Framework::BrowserExtension => write!(f, "Browser Extension (WXT)"),
+            Framework::Tauri => write!(f, "Tauri"),
+            Framework::Fumadocs => write!(f, "Fumadocs"),
+            Framework::Starlight => write!(f, "Starlight"),
+            Framework::Cli => write!(f, "Node CLI"),
+            Framework::Library => write!(f, "TypeScript library"),
+        }
+    }
+}
+
+impl Framework {
+    pub fn kind(&self) -> ProjectKind {
+        match self {
+            Framework::Fumadocs | Framework::Starlight => ProjectKind::Docs,
+            Framework::Cli => ProjectKind::Cli,
+            Framework::Library => ProjectKind::Library,
+            _ => ProjectKind::App,
+        }
+    }
+
+    /// Default directory name when the framework is generated under `apps/`.
+    pub fn app_dir_name(&self) -> &'static str {
+        match self {
+            Framework::NextJs => "web",
+            Framework::TanStackStart => "start",
+            Framework::ViteReact => "spa",
+            Framework::Hono => "api",
+            Framework::Express => "express",
+            Framework::Fastify => "fastify",
+            Framework::SolidStart => "solid",
+            Framework::Expo => "mobile",
+            Framework::BrowserExtension => "extension",
+            Framework::Tauri => "desktop",
+            Framework::Fumadocs => "docs",
+            Framework::Starlight => "starlight",
+            Framework::Cli => "cli",
+            Framework::Library => "lib",
+        }
+    }
+
+    /// Prefix that exposes an environment variable to browser code.
+    pub fn public_env_prefix(&self) -> &'static str {
+        match self {
+            Framework::NextJs | Framework::Fumadocs => "NEXT_PUBLIC_",
+            Framework::Expo => "EXPO_PUBLIC_",
+            Framework::Starlight => "PUBLIC_",
+            _ => "VITE_",
+        }
+    }
+
+    pub fn is_api_server(&self) -> bool {
+        matches!(
+            self,
+            Framework::Hono | Framework::Express | Framework::Fastify
+        )
+    }
+
+    /// Authentication options with a template for the framework. Auth.js
+    /// needs Next.js route handlers and middleware; Better Auth needs a server
+    /// API route, which the client-only frameworks lack.
+    pub fn auth_providers(&self) -> Vec<Auth> {
+        match self {
+            Framework::NextJs => vec![Auth::None, Auth::Clerk, Auth::NextAuth, Auth::BetterAuth],
+            Framework::TanStackStart => vec![Auth::None, Auth::Clerk, Auth::BetterAuth],
+            Framework::ViteReact | Framework::Expo | Framework::BrowserExtension => {
+                vec![Auth::None, Auth::Clerk]
+            }
+            _ => vec![Auth::None],
+        }
+    }
+
+    pub fn supports_tailwind(&self) -> bool {
+        !self.is_api_server() && matches!(self.kind(), ProjectKind::App)
+    }
+
+    pub fn supports_shadcn(&self) -> bool {
+        matches!(
+            self,
+            Framework::NextJs
+                | Framework::TanStackStart
+                | Framework::ViteReact
+                | Framework::BrowserExtension
+                | Framework::Tauri
+        )
+    }
+
+    /// Redis needs a server runtime; the rate limit example targets Next.js
+    /// middleware and TanStack server functions.
+    pub fn supports_redis(&self) -> bool {
+        matches!(self, Framework::NextJs | Framework::TanStackStart)
+    }
+
+    /// Databases other than SQL the generated project can wire up. An SPA or
+    /// mobile app has no server to hold a `DATABASE_URL`, and an API server
+    /// has no Convex client.
+    pub fn databases(&self) -> Vec<Database> {
+        match self {
+            Framework::NextJs | Framework::TanStackStart => vec![
+                Database::None,
+                Database::Convex,
+                Database::MongoDB,
+                Database::Supabase,
+                Database::Firebase,
+            ],
+            Framework::SolidStart => vec![Database::None, Database::Convex, Database::MongoDB],
+            Framework::ViteReact | Framework::Expo | Framework::Tauri => {
+                vec![Database::None, Database::Convex]
+            }
+            Framework::Hono | Framework::Express | Framework::Fastify => {
+                vec![Database::None, Database::MongoDB]
+            }
+            Framework::BrowserExtension
+            | Framework::Fumadocs
+            | Framework::Starlight
+            | Framework::Cli
+            | Framework::Library => vec![Database::None],
+        }
+    }
+
+    /// Whether the project has a server that can connect to a SQL database.
+    /// The prompts ask for the ORM and provider separately.
+    pub fn supports_sql(&self) -> bool {
+        matches!(
+            self,
+            Framework::NextJs
+                | Framework::TanStackStart
+                | Framework::SolidStart
+                | Framework::Hono
+                | Framework::Express
+                | Framework::Fastify
+        )
+    }
+}
+
+/// Authentication provider.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+// Variants are named after the libraries, several of which end in "Auth"
+#[allow(clippy::enum_variant_names)]
+pub enum Auth {
+    None,
+    Clerk,
+    NextAuth,
+    BetterAuth,
+}
+
+impl Auth {
+    /// Whether the provider can store its users in the database. Better Auth
+    /// keeps them in the project's own database through an adapter.
+    pub fn supports(&self, database: &Database) -> bool {
+        match self {
+            Auth::BetterAuth => matches!(
+                database,
+                Database::Sql(Orm::Drizzle | Orm::Prisma, _) | Database::MongoDB
+            ),
+            Auth::None | Auth::Clerk | Auth::NextAuth => true,
+        }
+    }
+}
+
+impl fmt::Display for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Auth::None => write!(f, "None"),
+            Auth::Clerk => write!(f, "Clerk"),
+            Auth::NextAuth => write!(f, "Auth.js (NextAuth v5)"),
+            Auth::BetterAuth => write!(f, "Better Auth"),
+        }
+    }
+}
+
+/// Redis client for caching and rate limiting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Redis {
+    None,
+    Upstash,
+    Ioredis,
+}
+
+impl fmt::Display for Redis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Redis::None => write!(f, "None"),
+            Redis::Upstash => write!(f, "Upstash (@upstash/redis)"),
+            Redis::Ioredis => write!(f, "Self-hosted (ioredis)"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Runtime {
+    Node,
+    Bun,
+}
+
+impl Runtime {
+    /// Whether the runtime can load the database's driver. Bun can't load
+    /// native Node addons such as `better-sqlite3`, which the local SQLite
+    /// setup uses outside Prisma.
+    pub fn supports(&self, database: &Database) -> bool {
+        match self {
+            Runtime::Bun => !matches!(
+                database,
+                Database::Sql(Orm::Drizzle | Orm::Kysely | Orm::None, SqlProvider::Sqlite)
+            ),
+            Runtime::Node => true,
+        }
+    }
+}
+
+impl fmt::Display for Runtime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Runtime::Node => write!(f, "Node.js"),
+            Runtime::Bun => write!(f, "Bun"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CliParser {
+    Commander,
+    Citty,
+}
+
+impl fmt::Display for CliParser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliParser::Commander => write!(f, "Commander"),
+            CliParser::Citty => write!(f, "citty"),
         }
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ClientRouter {
+    None,
+    TanStackRouter,
+    ReactRouter,
+}
+
+impl fmt::Display for ClientRouter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientRouter::None => write!(f, "None"),
+            ClientRouter::TanStackRouter => write!(f, "TanStack Router"),
+            ClientRouter::ReactRouter => write!(f, "React Router"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Database {
     Convex,
-    NeonDrizzle,
+    Sql(Orm, SqlProvider),
+    MongoDB,
+    Supabase,
+    Firebase,
     None,
 }
 
@@ -27,12 +313,77 @@ impl fmt::Display for Database {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Database::Convex => write!(f, "Convex"),
-            Database::NeonDrizzle => write!(f, "Neon + Drizzle"),
+            Database::Sql(Orm::None, provider) => write!(f, "{}", provider),
+            Database::Sql(orm, provider) => write!(f, "{} + {}", orm, provider),
+            Database::MongoDB => write!(f, "MongoDB"),
+            Database::Supabase => write!(f, "Supabase"),
+            Database::Firebase => write!(f, "Firebase"),
             Database::None => write!(f, "None"),
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Orm {
+    Drizzle,
+    Prisma,
+    Kysely,
+    None,
+}
+
+impl Orm {
+    pub fn all() -> [Orm; 4] {
+        [Orm::Drizzle, Orm::Prisma, Orm::Kysely, Orm::None]
+    }
+
+    /// Providers the ORM can connect to. Prisma has no stable libSQL driver.
+    pub fn providers(&self) -> Vec<SqlProvider> {
+        let mut providers = vec![
+            SqlProvider::Neon,
+            SqlProvider::Postgres,
+            SqlProvider::Turso,
+            SqlProvider::Sqlite,
+            SqlProvider::PlanetScale,
+        ];
+        if matches!(self, Orm::Prisma) {
+            providers.retain(|p| !matches!(p, SqlProvider::Turso));
+        }
+        providers
+    }
+}
+
+impl fmt::Display for Orm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Orm::Drizzle => write!(f, "Drizzle"),
+            Orm::Prisma => write!(f, "Prisma"),
+            Orm::Kysely => write!(f, "Kysely"),
+            Orm::None => write!(f, "None (driver only)"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SqlProvider {
+    Neon,
+    Postgres,
+    Turso,
+    Sqlite,
+    PlanetScale,
+}
+
+impl fmt::Display for SqlProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SqlProvider::Neon => write!(f, "Neon"),
+            SqlProvider::Postgres => write!(f, "PostgreSQL"),
+            SqlProvider::Turso => write!(f, "Turso"),
+            SqlProvider::Sqlite => write!(f, "SQLite"),
+            SqlProvider::PlanetScale => write!(f, "PlanetScale"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PackageManager {
     Npm,
@@ -57,9 +408,16 @@ pub struct ProjectConfig {
     pub name: String,
     pub framework: Framework,
     pub database: Database,
-    pub use_clerk: bool,
+    pub router: ClientRouter,
+    pub runtime: Runtime,
+    pub cli_parser: CliParser,
+    pub auth: Auth,
     pub use_tailwind: bool,
     pub use_shadcn: bool,
+    pub redis: Redis,
+    /// Run PostgreSQL in a `docker-compose.yml` service, when the database
+    /// uses the local PostgreSQL provider.
+    pub docker_postgres: bool,
     pub package_manager: PackageManager,
     pub init_git: bool,
 }
@@ -70,11 +428,156 @@ impl ProjectConfig {
             name,
             framework: Framework::NextJs,
             database: Database::None,
-            use_clerk: false,
+            router: ClientRouter::None,
+            runtime: Runtime::Node,
+            cli_parser: CliParser::Commander,
+            auth: Auth::None,
             use_tailwind: false,
             use_shadcn: false,
+            redis: Redis::None,
+            docker_postgres: false,
             package_manager,
             init_git: true,
         }
     }
 }
+
+/// A Turborepo workspace: each app is generated into `apps/<name>` and the
+/// shared code lives in `packages/*`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonorepoConfig {
+    pub name: String,
+    pub apps: Vec<ProjectConfig>,
+    pub package_manager: PackageManager,
+    pub init_git: bool,
+}
+
+impl MonorepoConfig {
+    pub fn default(name: String, package_manager: PackageManager) -> Self {
+        Self {
+            apps: vec![ProjectConfig::default(
+                "web".to_string(),
+                package_manager.clone(),
+            )],
+            name,
+            package_manager,
+            init_git: true,
+        }
+    }
+
+    pub fn uses_shadcn(&self) -> bool {
+        self.apps.iter().any(|app| app.use_shadcn)
+    }
+
+    /// The first app using Drizzle; the shared `db` package connects through
+    /// its provider.
+    pub fn drizzle_app(&self) -> Option<&ProjectConfig> {
+        self.apps
+            .iter()
+            .find(|app| matches!(app.database, Database::Sql(Orm::Drizzle, _)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drizzle_app_is_the_first_app_using_drizzle() {
+        let mut config = MonorepoConfig::default("repo".to_string(), PackageManager::Pnpm);
+        config.apps = vec![
+            ProjectConfig {
+                database: Database::Sql(Orm::Prisma, SqlProvider::Postgres),
+                ..fixtures::project("web")
+            },
+            ProjectConfig {
+                database: Database::Sql(Orm::Drizzle, SqlProvider::Neon),
+                ..fixtures::project("api")
+            },
+            ProjectConfig {
+                database: Database::Sql(Orm::Drizzle, SqlProvider::Sqlite),
+                ..fixtures::project("admin")
+            },
+        ];
+        assert_eq!(
+            config.drizzle_app().map(|app| app.name.as_str()),
+            Some("api")
+        );
+    }
+
+    #[test]
+    fn bun_rejects_better_sqlite3() {
+        for orm in [Orm::Drizzle, Orm::Kysely, Orm::None] {
+            let sqlite = Database::Sql(orm.clone(), SqlProvider::Sqlite);
+            assert!(!Runtime::Bun.supports(&sqlite));
+            assert!(Runtime::Node.supports(&sqlite));
+            assert!(Runtime::Bun.supports(&Database::Sql(orm, SqlProvider::Turso)));
+        }
+        assert!(Runtime::Bun.supports(&Database::Sql(Orm::Prisma, SqlProvider::Sqlite)));
+    }
+
+    #[test]
+    fn drizzle_app_is_none_without_drizzle() {
+        let mut config = MonorepoConfig::default("repo".to_string(), PackageManager::Pnpm);
+        assert!(config.drizzle_app().is_none());
+        config.apps.push(ProjectConfig {
+            database: Database::Convex,
+            ..fixtures::project("api")
+        });
+        assert!(config.drizzle_app().is_none());
+    }
+
+    #[test]
+    fn better_auth_needs_a_database_adapter() {
+        for database in [
+            Database::Sql(Orm::Drizzle, SqlProvider::Postgres),
+            Database::Sql(Orm::Prisma, SqlProvider::Sqlite),
+            Database::MongoDB,
+        ] {
+            assert!(Auth::BetterAuth.supports(&database));
+        }
+        for database in [
+            Database::None,
+            Database::Convex,
+            Database::Supabase,
+            Database::Firebase,
+            Database::Sql(Orm::Kysely, SqlProvider::Postgres),
+            Database::Sql(Orm::None, SqlProvider::Neon),
+        ] {
+            assert!(!Auth::BetterAuth.supports(&database));
+            assert!(Auth::Clerk.supports(&database));
+            assert!(Auth::NextAuth.supports(&database));
+        }
+    }
+
+    #[test]
+    fn sql_is_offered_alongside_the_other_databases() {
+        assert!(Framework::Hono.supports_sql());
+        assert!(!Framework::ViteReact.supports_sql());
+        assert!(
+            !Framework::NextJs
+                .databases()
+                .iter()
+                .any(|database| matches!(database, Database::Sql(..)))
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod fixtures {
+    use super::*;
+
+    /// A Next.js project with every option at its default; tests override
+    /// the fields they exercise with struct update syntax.
+    pub fn project(name: &str) -> ProjectConfig {
+        ProjectConfig::default(name.to_string(), PackageManager::Pnpm)
+    }
+
+    /// An empty directory under the system temp dir, unique to the test.
+    pub fn temp_root(test: &str) -> std::path::PathBuf {
+        let root = std::env::temp_dir().join(format!("cp-cli-{}-{}", test, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        root
+    }
+}
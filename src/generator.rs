@@ -1,12 +1,15 @@
-use crate::config::ProjectConfig;
-use crate::templates;
+use crate::config::{Framework, MonorepoConfig, ProjectConfig};
+use crate::templates::{self, database, monorepo};
+use crate::utils::workspace::Workspace;
 use crate::utils::{fs, git};
-use anyhow::Result;
+use anyhow::{Context, Result};
 use console::style;
 use indicatif::{ProgressBar, ProgressStyle};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub fn generate_project(config: ProjectConfig) -> Result<PathBuf> {
+    templates::check_supported(&config)?;
+
     let spinner = ProgressBar::new_spinner();
     spinner.set_style(
         ProgressStyle::default_spinner()
@@ -53,6 +56,129 @@ pub fn generate_project(config: ProjectConfig) -> Result<PathBuf> {
     Ok(project_path)
 }
 
+pub fn generate_monorepo(config: MonorepoConfig) -> Result<PathBuf> {
+    for app in &config.apps {
+        templates::check_supported(app)?;
+    }
+    if let Some(drizzle_app) = config.drizzle_app() {
+        for app in &config.apps {
+            monorepo::check_shared_db(drizzle_app, app)?;
+        }
+    }
+
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg}")
+            .unwrap(),
+    );
+
+    // Step 1: Create the workspace root and shared packages
+    spinner.set_message("Creating workspace...");
+    let root_path = fs::create_project_directory(&config.name)?;
+    monorepo::generate_monorepo_root(&config, &root_path)?;
+    spinner.finish_with_message("✓ Workspace created");
+
+    // Step 2: Generate each app under apps/ and link it to the shared packages
+    for app in &config.apps {
+        let spinner = ProgressBar::new_spinner();
+        spinner.set_style(
+            ProgressStyle::default_spinner()
+                .template("{spinner:.green} {msg}")
+                .unwrap(),
+        );
+        spinner.set_message(format!("Generating apps/{}...", app.name));
+        let app_path = root_path.join("apps").join(&app.name);
+        fs::create_directory(&app_path)?;
+        templates::generate_project(app, &app_path)?;
+        monorepo::link_app(app, &app_path, &root_path)?;
+        monorepo::link_services(app, &app_path)?;
+        spinner.finish_with_message(format!("✓ apps/{} generated", app.name));
+    }
+
+    // Step 3: Initialize git repository if requested
+    if config.init_git {
+        let spinner = ProgressBar::new_spinner();
+        spinner.set_style(
+            ProgressStyle::default_spinner()
+                .template("{spinner:.green} {msg}")
+                .unwrap(),
+        );
+        spinner.set_message("Initializing git repository...");
+        match git::init_git_repo(&root_path) {
+            Ok(_) => {
+                spinner.finish_with_message("✓ Git repository initialized");
+            }
+            Err(e) => {
+                let msg = format!("⚠ Git initialization skipped: {}", e);
+                spinner.finish_with_message(msg);
+            }
+        }
+    }
+
+    Ok(root_path)
+}
+
+/// Generates an app inside an existing workspace. `config.name` is the path
+/// to create, relative to the current directory (e.g. `apps/admin`).
+pub fn generate_workspace_app(config: ProjectConfig, workspace: &Workspace) -> Result<PathBuf> {
+    let app_path = std::env::current_dir()?.join(&config.name);
+    let relative_path = app_path
+        .strip_prefix(&workspace.root)
+        .with_context(|| format!("'{}' is outside the workspace", config.name))?
+        .to_path_buf();
+
+    // The package is named after the directory, not the path
+    let mut app = config;
+    app.name = Path::new(&app.name)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .with_context(|| format!("Invalid app path: {}", app.name))?
+        .to_string();
+    app.init_git = false;
+
+    templates::check_supported(&app)?;
+    monorepo::check_db_package(&app, &workspace.root)?;
+
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg}")
+            .unwrap(),
+    );
+
+    // Step 1: Create app directory
+    spinner.set_message("Creating app directory...");
+    if app_path.exists() {
+        anyhow::bail!("Directory '{}' already exists", relative_path.display());
+    }
+    fs::create_directory(&app_path)?;
+    spinner.finish_with_message("✓ App directory created");
+
+    // Step 2: Generate framework-specific files and use the shared packages
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg}")
+            .unwrap(),
+    );
+    spinner.set_message("Generating project files...");
+    templates::generate_project(&app, &app_path)?;
+    monorepo::link_app(&app, &app_path, &workspace.root)?;
+    spinner.finish_with_message("✓ Project files generated");
+
+    // Step 3: Add the app to the workspace globs if they don't cover it
+    if !workspace.includes(&relative_path) {
+        workspace.register(&relative_path)?;
+        println!(
+            "✓ Registered {} in the workspace config",
+            relative_path.display()
+        );
+    }
+
+    Ok(app_path)
+}
+
 pub fn print_success_message(config: &ProjectConfig, _project_path: &PathBuf) {
     println!();
     println!(
@@ -64,6 +190,65 @@ pub fn print_success_message(config: &ProjectConfig, _project_path: &PathBuf) {
     println!("{}", style("Next steps:").cyan().bold());
     println!("  cd {}", config.name);
     println!("  {}", config.package_manager.install_cmd());
+    for step in database::setup_steps(config) {
+        println!("  {}", step);
+    }
+    if matches!(config.framework, Framework::Tauri) {
+        println!("  {}", config.package_manager.run_cmd("tauri dev"));
+        println!();
+        println!("{}", style("Check the Rust backend:").cyan().bold());
+        println!("  cd src-tauri && cargo check");
+    } else {
+        println!("  {}", config.package_manager.dev_cmd());
+    }
+    println!();
+}
+
+pub fn print_monorepo_success_message(config: &MonorepoConfig, _project_path: &PathBuf) {
+    println!();
+    println!(
+        "{} {}",
+        style("✨").green(),
+        style("Monorepo created successfully!").green().bold()
+    );
+    println!();
+    println!("{}", style("Apps:").cyan().bold());
+    for app in &config.apps {
+        println!("  apps/{} ({})", app.name, app.framework);
+    }
+    println!();
+    println!("{}", style("Next steps:").cyan().bold());
+    println!("  cd {}", config.name);
+    println!("  {}", config.package_manager.install_cmd());
+    if monorepo::has_services(config) {
+        println!("  {}", config.package_manager.run_cmd("db:up"));
+    }
+    println!("  {}", config.package_manager.dev_cmd());
+    println!();
+}
+
+pub fn print_workspace_app_success_message(
+    config: &ProjectConfig,
+    workspace: &Workspace,
+    _project_path: &PathBuf,
+) {
+    println!();
+    println!(
+        "{} {}",
+        style("✨").green(),
+        style("App added to the workspace!").green().bold()
+    );
+    println!();
+    println!("{}", style("Next steps:").cyan().bold());
+    println!(
+        "  {} (from {})",
+        config.package_manager.install_cmd(),
+        workspace.root.display()
+    );
+    println!("  cd {}", config.name);
+    for step in database::setup_steps(config) {
+        println!("  {}", step);
+    }
     println!("  {}", config.package_manager.dev_cmd());
     println!();
 }
@@ -7,8 +7,12 @@ mod utils;
 use clap::{Parser, Subcommand};
 use config::PackageManager;
 use console::style;
-use generator::{generate_project, print_success_message};
-use prompts::collect_project_config;
+use generator::{
+    generate_monorepo, generate_project, generate_workspace_app, print_monorepo_success_message,
+    print_success_message, print_workspace_app_success_message,
+};
+use prompts::{collect_monorepo_config, collect_project_config, confirm_add_to_workspace};
+use utils::workspace::Workspace;
 
 #[derive(Parser)]
 #[command(name = "cp-cli")]
@@ -31,6 +35,12 @@ enum Commands {
         /// Skip prompts and use defaults
         #[arg(short, long)]
         yes: bool,
+        /// Create a Turborepo monorepo with apps/ and shared packages/
+        #[arg(long)]
+        monorepo: bool,
+        /// Run local PostgreSQL in docker-compose without asking
+        #[arg(long)]
+        docker: bool,
     },
 }
 
@@ -38,7 +48,13 @@ fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Some(Commands::Create { name, pm, yes }) => {
+        Some(Commands::Create {
+            name,
+            pm,
+            yes,
+            monorepo,
+            docker,
+        }) => {
             let pm_override = pm.and_then(|p| {
                 match p.as_str() {
                     "npm" => Some(PackageManager::Npm),
@@ -49,12 +65,82 @@ fn main() {
                 }
             });
 
-            match collect_project_config(name, pm_override, yes) {
-                Ok(config) => {
-                    match generate_project(config.clone()) {
+            if monorepo {
+                // A workspace nested in another one would be a package of it
+                if let Some(workspace) = std::env::current_dir()
+                    .ok()
+                    .and_then(|dir| Workspace::detect(&dir))
+                {
+                    eprintln!("{} {}", style("✗").red(), style("Error:").red().bold());
+                    eprintln!(
+                        "Already inside the {} workspace at {}; run create without --monorepo to add an app to it",
+                        workspace.package_manager,
+                        workspace.root.display()
+                    );
+                    std::process::exit(1);
+                }
+                match collect_monorepo_config(name, pm_override, yes, docker) {
+                    Ok(config) => match generate_monorepo(config.clone()) {
                         Ok(project_path) => {
-                            print_success_message(&config, &project_path);
+                            print_monorepo_success_message(&config, &project_path);
+                        }
+                        Err(e) => {
+                            eprintln!("{} {}", style("✗").red(), style("Error:").red().bold());
+                            eprintln!("{}", e);
+                            std::process::exit(1);
                         }
+                    },
+                    Err(e) => {
+                        eprintln!("{} {}", style("✗").red(), style("Error:").red().bold());
+                        eprintln!("{}", e);
+                        std::process::exit(1);
+                    }
+                }
+                return;
+            }
+
+            // Inside an existing workspace, add the app to it and reuse its
+            // package manager. Outside its root and app directories, ask
+            // first, since the workspace may just be an enclosing checkout.
+            let workspace = std::env::current_dir().ok().and_then(|dir| {
+                let workspace = Workspace::detect(&dir)?;
+                let join = workspace.contains_apps_in(&dir)
+                    || (!yes && confirm_add_to_workspace(&workspace).unwrap_or(false));
+                join.then_some(workspace)
+            });
+            let pm_override = match &workspace {
+                Some(workspace) => {
+                    println!(
+                        "{}",
+                        style(format!(
+                            "Adding to the {} workspace at {}",
+                            workspace.package_manager,
+                            workspace.root.display()
+                        ))
+                        .dim()
+                    );
+                    Some(workspace.package_manager.clone())
+                }
+                None => pm_override,
+            };
+
+            match collect_project_config(name, pm_override, yes, workspace.is_some(), docker) {
+                Ok(config) => {
+                    let result = match &workspace {
+                        Some(workspace) => generate_workspace_app(config.clone(), workspace),
+                        None => generate_project(config.clone()),
+                    };
+                    match result {
+                        Ok(project_path) => match &workspace {
+                            Some(workspace) => {
+                                print_workspace_app_success_message(
+                                    &config,
+                                    workspace,
+                                    &project_path,
+                                );
+                            }
+                            None => print_success_message(&config, &project_path),
+                        },
                         Err(e) => {
                             eprintln!("{} {}", style("✗").red(), style("Error:").red().bold());
                             eprintln!("{}", e);
@@ -81,6 +167,7 @@ fn main() {
             println!("  cp-cli create <name>     Create a new project");
             println!("  cp-cli create <name> --pm bun    Override package manager");
             println!("  cp-cli create <name> --yes       Skip prompts");
+            println!("  cp-cli create <name> --monorepo  Create a Turborepo monorepo");
         }
     }
 }
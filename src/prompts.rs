@@ -1,101 +1,452 @@
-use crate::config::{Database, Framework, PackageManager, ProjectConfig};
+use crate::config::{
+    Auth, CliParser, ClientRouter, Database, Framework, MonorepoConfig, Orm, PackageManager,
+    ProjectConfig, ProjectKind, Redis, Runtime, SqlProvider,
+};
+use crate::templates::monorepo;
+use crate::utils::workspace::Workspace;
+use console::style;
 use dialoguer::{Confirm, Input, MultiSelect, Select};
 
+/// Collects the config for a single project. Inside an existing workspace
+/// the app shares the root's repository, so no git repository is created.
 pub fn collect_project_config(
     name: Option<String>,
     pm_override: Option<PackageManager>,
     skip_prompts: bool,
+    in_workspace: bool,
+    docker_postgres: bool,
 ) -> anyhow::Result<ProjectConfig> {
-    let project_name = if let Some(name) = name {
-        name
-    } else if skip_prompts {
-        "my-app".to_string()
-    } else {
-        Input::new()
-            .with_prompt("Project name")
-            .default("my-app".to_string())
-            .interact_text()?
+    let project_name = prompt_project_name(name, "my-app", skip_prompts)?;
+    let package_manager = prompt_package_manager(pm_override, skip_prompts)?;
+
+    if skip_prompts {
+        let mut config = ProjectConfig::default(project_name, package_manager);
+        config.init_git = !in_workspace;
+        return Ok(config);
+    }
+
+    // Project kind selection
+    let kind_options = vec![
+        "App",
+        "CLI tool",
+        "Library (npm package)",
+        "Documentation site",
+    ];
+    let kind_selected = Select::new()
+        .with_prompt("What are you building?")
+        .items(&kind_options)
+        .default(0)
+        .interact()?;
+
+    let kind = match kind_selected {
+        0 => ProjectKind::App,
+        1 => ProjectKind::Cli,
+        2 => ProjectKind::Library,
+        3 => ProjectKind::Docs,
+        _ => ProjectKind::App,
     };
 
-    let package_manager = if let Some(pm) = pm_override {
-        pm
-    } else if skip_prompts {
-        PackageManager::detect()
+    // Framework selection
+    let framework_options = framework_options(&kind);
+    let framework = if framework_options.len() == 1 {
+        framework_options[0].1.clone()
     } else {
-        let pm_options = vec!["npm", "pnpm", "yarn", "bun"];
-        let selected = Select::new()
-            .with_prompt("Package manager")
-            .items(&pm_options)
+        let framework_labels: Vec<&str> =
+            framework_options.iter().map(|(label, _)| *label).collect();
+        let framework_selected = Select::new()
+            .with_prompt("Select framework")
+            .items(&framework_labels)
             .default(0)
             .interact()?;
 
-        match selected {
-            0 => PackageManager::Npm,
-            1 => PackageManager::Pnpm,
-            2 => PackageManager::Yarn,
-            3 => PackageManager::Bun,
-            _ => PackageManager::Npm,
+        framework_options[framework_selected].1.clone()
+    };
+
+    let mut config =
+        collect_framework_options(project_name, framework, package_manager, docker_postgres)?;
+
+    // Git initialization
+    config.init_git = !in_workspace
+        && Confirm::new()
+            .with_prompt("Initialize git repository?")
+            .default(true)
+            .interact()?;
+
+    Ok(config)
+}
+
+/// Asks whether a project created below a workspace, but outside its root
+/// and app directories, should join it.
+pub fn confirm_add_to_workspace(workspace: &Workspace) -> anyhow::Result<bool> {
+    Ok(Confirm::new()
+        .with_prompt(format!(
+            "Add the project to the {} workspace at {}?",
+            workspace.package_manager,
+            workspace.root.display()
+        ))
+        .default(false)
+        .interact()?)
+}
+
+pub fn collect_monorepo_config(
+    name: Option<String>,
+    pm_override: Option<PackageManager>,
+    skip_prompts: bool,
+    docker_postgres: bool,
+) -> anyhow::Result<MonorepoConfig> {
+    let project_name = prompt_project_name(name, "my-monorepo", skip_prompts)?;
+    let package_manager = prompt_package_manager(pm_override, skip_prompts)?;
+
+    if skip_prompts {
+        return Ok(MonorepoConfig::default(project_name, package_manager));
+    }
+
+    // App selection; libraries belong in packages/ rather than apps/
+    let mut app_options = Vec::new();
+    for kind in [ProjectKind::App, ProjectKind::Cli, ProjectKind::Docs] {
+        app_options.extend(framework_options(&kind));
+    }
+    let app_labels: Vec<&str> = app_options.iter().map(|(label, _)| *label).collect();
+    let app_selections = loop {
+        let selections = MultiSelect::new()
+            .with_prompt("Select apps (space to select, enter to confirm)")
+            .items(&app_labels)
+            .defaults(&[true])
+            .interact()?;
+        if !selections.is_empty() {
+            break selections;
         }
+        println!("{}", style("  Select at least one app").yellow());
     };
 
+    let mut apps: Vec<ProjectConfig> = Vec::new();
+    for i in app_selections {
+        let framework = app_options[i].1.clone();
+        println!();
+        println!("{}", style(format!("{} app", framework)).cyan().bold());
+
+        let app_name = loop {
+            let app_name: String = Input::new()
+                .with_prompt("Directory name (under apps/)")
+                .default(framework.app_dir_name().to_string())
+                .interact_text()?;
+            if apps.iter().any(|app| app.name == app_name) {
+                println!("{}", style("  That directory is already taken").yellow());
+            } else {
+                break app_name;
+            }
+        };
+
+        // Drizzle apps share packages/db, so they must agree on its schema
+        let app = loop {
+            let app = collect_framework_options(
+                app_name.clone(),
+                framework.clone(),
+                package_manager.clone(),
+                docker_postgres,
+            )?;
+            let drizzle_app = apps
+                .iter()
+                .find(|app| matches!(app.database, Database::Sql(Orm::Drizzle, _)));
+            match drizzle_app.map(|drizzle_app| monorepo::check_shared_db(drizzle_app, &app)) {
+                Some(Err(e)) => println!("{}", style(format!("  {}", e)).yellow()),
+                _ => break app,
+            }
+        };
+        apps.push(app);
+    }
+    println!();
+
+    // Git initialization
+    let init_git = Confirm::new()
+        .with_prompt("Initialize git repository?")
+        .default(true)
+        .interact()?;
+
+    Ok(MonorepoConfig {
+        name: project_name,
+        apps,
+        package_manager,
+        init_git,
+    })
+}
+
+fn prompt_project_name(
+    name: Option<String>,
+    default: &str,
+    skip_prompts: bool,
+) -> anyhow::Result<String> {
+    if let Some(name) = name {
+        Ok(name)
+    } else if skip_prompts {
+        Ok(default.to_string())
+    } else {
+        Ok(Input::new()
+            .with_prompt("Project name")
+            .default(default.to_string())
+            .interact_text()?)
+    }
+}
+
+fn prompt_package_manager(
+    pm_override: Option<PackageManager>,
+    skip_prompts: bool,
+) -> anyhow::Result<PackageManager> {
+    if let Some(pm) = pm_override {
+        return Ok(pm);
+    }
     if skip_prompts {
-        return Ok(ProjectConfig::default(project_name, package_manager));
+        return Ok(PackageManager::detect());
     }
 
-    // Framework selection
-    let framework_options = vec!["Next.js", "TanStack Start"];
-    let framework_selected = Select::new()
-        .with_prompt("Select framework")
-        .items(&framework_options)
+    let pm_options = vec!["npm", "pnpm", "yarn", "bun"];
+    let selected = Select::new()
+        .with_prompt("Package manager")
+        .items(&pm_options)
         .default(0)
         .interact()?;
 
-    let framework = match framework_selected {
-        0 => Framework::NextJs,
-        1 => Framework::TanStackStart,
-        _ => Framework::NextJs,
+    Ok(match selected {
+        0 => PackageManager::Npm,
+        1 => PackageManager::Pnpm,
+        2 => PackageManager::Yarn,
+        3 => PackageManager::Bun,
+        _ => PackageManager::Npm,
+    })
+}
+
+/// Frameworks offered for each project kind, with their prompt labels.
+fn framework_options(kind: &ProjectKind) -> Vec<(&'static str, Framework)> {
+    match kind {
+        ProjectKind::App => vec![
+            ("Next.js", Framework::NextJs),
+            ("TanStack Start", Framework::TanStackStart),
+            ("Vite + React (SPA)", Framework::ViteReact),
+            ("Hono (API server)", Framework::Hono),
+            ("Express (Node API server)", Framework::Express),
+            ("Fastify (Node API server)", Framework::Fastify),
+            ("SolidStart", Framework::SolidStart),
+            ("Expo (React Native)", Framework::Expo),
+            ("Browser extension (WXT)", Framework::BrowserExtension),
+            ("Tauri (desktop app)", Framework::Tauri),
+        ],
+        ProjectKind::Cli => vec![("Node CLI (TypeScript)", Framework::Cli)],
+        ProjectKind::Library => vec![("TypeScript library", Framework::Library)],
+        ProjectKind::Docs => vec![
+            ("Fumadocs (Next.js)", Framework::Fumadocs),
+            ("Starlight (Astro)", Framework::Starlight),
+        ],
+    }
+}
+
+/// Prompts for the options that depend on the chosen framework. Git is left
+/// to the caller since apps inside a monorepo share the root repository.
+/// With `docker_postgres`, local PostgreSQL runs in docker-compose without
+/// asking.
+fn collect_framework_options(
+    name: String,
+    framework: Framework,
+    package_manager: PackageManager,
+    docker_postgres: bool,
+) -> anyhow::Result<ProjectConfig> {
+    // Runtime selection (API servers only)
+    let runtime = if matches!(framework, Framework::Hono) {
+        let runtime_options = vec!["Node.js", "Bun"];
+        let runtime_selected = Select::new()
+            .with_prompt("Select runtime")
+            .items(&runtime_options)
+            .default(0)
+            .interact()?;
+
+        match runtime_selected {
+            0 => Runtime::Node,
+            1 => Runtime::Bun,
+            _ => Runtime::Node,
+        }
+    } else {
+        Runtime::Node
     };
 
-    // Database selection
-    let database_options = vec!["None", "Convex", "Neon + Drizzle"];
-    let database_selected = Select::new()
-        .with_prompt("Select database")
-        .items(&database_options)
-        .default(0)
-        .interact()?;
+    // Argument parser selection (CLI tools only)
+    let cli_parser = if matches!(framework, Framework::Cli) {
+        let parser_options = vec!["Commander", "citty"];
+        let parser_selected = Select::new()
+            .with_prompt("Select argument parser")
+            .items(&parser_options)
+            .default(0)
+            .interact()?;
 
-    let database = match database_selected {
-        0 => Database::None,
-        1 => Database::Convex,
-        2 => Database::NeonDrizzle,
-        _ => Database::None,
+        match parser_selected {
+            0 => CliParser::Commander,
+            1 => CliParser::Citty,
+            _ => CliParser::Commander,
+        }
+    } else {
+        CliParser::Commander
     };
 
-    // Optional features
-    let feature_options = vec!["Clerk (Auth)", "Tailwind CSS", "shadcn/ui"];
-    let feature_selections = MultiSelect::new()
-        .with_prompt("Select optional features (space to select, enter to confirm)")
-        .items(&feature_options)
-        .interact()?;
+    // Client-side routing (Vite SPAs only)
+    let router = if matches!(framework, Framework::ViteReact | Framework::Tauri) {
+        let router_options = vec!["None", "TanStack Router", "React Router"];
+        let router_selected = Select::new()
+            .with_prompt("Select router")
+            .items(&router_options)
+            .default(0)
+            .interact()?;
 
-    let use_clerk = feature_selections.contains(&0);
-    let use_tailwind = feature_selections.contains(&1);
-    let use_shadcn = feature_selections.contains(&2);
+        match router_selected {
+            0 => ClientRouter::None,
+            1 => ClientRouter::TanStackRouter,
+            2 => ClientRouter::ReactRouter,
+            _ => ClientRouter::None,
+        }
+    } else {
+        ClientRouter::None
+    };
 
-    // Git initialization
-    let init_git = Confirm::new()
-        .with_prompt("Initialize git repository?")
-        .default(true)
-        .interact()?;
+    // Database selection (skipped when the framework only supports one); the
+    // SQL option goes on to ask for an ORM and provider
+    let database_options = framework.databases();
+    let mut database_labels: Vec<String> = database_options
+        .iter()
+        .map(|database| database.to_string())
+        .collect();
+    if framework.supports_sql() {
+        database_labels.push("SQL database (Drizzle, Prisma, Kysely)".to_string());
+    }
+    let database = if database_labels.len() == 1 {
+        database_options[0].clone()
+    } else {
+        let database_selected = Select::new()
+            .with_prompt("Select database")
+            .items(&database_labels)
+            .default(0)
+            .interact()?;
+
+        match database_options.get(database_selected) {
+            Some(database) => database.clone(),
+            None => {
+                let orm_options = Orm::all();
+                let orm_selected = Select::new()
+                    .with_prompt("Select ORM")
+                    .items(&orm_options)
+                    .default(0)
+                    .interact()?;
+                let orm = orm_options[orm_selected].clone();
+
+                let provider_options: Vec<SqlProvider> = orm
+                    .providers()
+                    .into_iter()
+                    .filter(|provider| {
+                        runtime.supports(&Database::Sql(orm.clone(), provider.clone()))
+                    })
+                    .collect();
+                let provider_selected = Select::new()
+                    .with_prompt("Select database provider")
+                    .items(&provider_options)
+                    .default(0)
+                    .interact()?;
+
+                Database::Sql(orm, provider_options[provider_selected].clone())
+            }
+        }
+    };
+
+    // Local PostgreSQL can run in docker-compose instead of an existing server
+    let docker_postgres = matches!(database, Database::Sql(_, SqlProvider::Postgres))
+        && (docker_postgres
+            || Confirm::new()
+                .with_prompt("Run PostgreSQL in docker-compose?")
+                .default(false)
+                .interact()?);
+
+    // Optional features, limited to the ones the framework supports
+    let mut feature_options = Vec::new();
+    let mut unsupported = Vec::new();
+    for (label, supported) in [
+        ("Authentication", framework.auth_providers().len() > 1),
+        ("Tailwind CSS", framework.supports_tailwind()),
+        ("shadcn/ui", framework.supports_shadcn()),
+        ("Redis (caching, rate limiting)", framework.supports_redis()),
+    ] {
+        if supported {
+            feature_options.push(label);
+        } else {
+            unsupported.push(label);
+        }
+    }
+
+    if !unsupported.is_empty() {
+        println!(
+            "{}",
+            style(format!(
+                "  Not available with {}: {}",
+                framework,
+                unsupported.join(", ")
+            ))
+            .dim()
+        );
+    }
+
+    let feature_selections = if feature_options.is_empty() {
+        Vec::new()
+    } else {
+        MultiSelect::new()
+            .with_prompt("Select optional features (space to select, enter to confirm)")
+            .items(&feature_options)
+            .interact()?
+    };
+    let selected_features: Vec<&str> = feature_selections
+        .iter()
+        .map(|&i| feature_options[i])
+        .collect();
+
+    let use_tailwind = selected_features.contains(&"Tailwind CSS");
+    let use_shadcn = selected_features.contains(&"shadcn/ui");
+
+    let auth = if selected_features.contains(&"Authentication") {
+        let auth_options: Vec<Auth> = framework
+            .auth_providers()
+            .into_iter()
+            .filter(|auth| !matches!(auth, Auth::None) && auth.supports(&database))
+            .collect();
+        let auth_selected = Select::new()
+            .with_prompt("Select authentication")
+            .items(&auth_options)
+            .default(0)
+            .interact()?;
+
+        auth_options[auth_selected].clone()
+    } else {
+        Auth::None
+    };
+
+    let redis = if selected_features.contains(&"Redis (caching, rate limiting)") {
+        let redis_options = [Redis::Upstash, Redis::Ioredis];
+        let redis_selected = Select::new()
+            .with_prompt("Select Redis client")
+            .items(&redis_options)
+            .default(0)
+            .interact()?;
+
+        redis_options[redis_selected].clone()
+    } else {
+        Redis::None
+    };
 
     Ok(ProjectConfig {
-        name: project_name,
+        name,
         framework,
         database,
-        use_clerk,
+        router,
+        runtime,
+        cli_parser,
+        auth,
         use_tailwind,
         use_shadcn,
+        redis,
+        docker_postgres,
         package_manager,
-        init_git,
+        init_git: false,
     })
 }
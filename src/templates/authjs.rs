@@ -0,0 +1,296 @@
+use crate::config::{Database, Orm, ProjectConfig, SqlProvider};
+use crate::utils::{fs, secret};
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+const PG_SCHEMA: &str = r#"import { integer, pgTable, primaryKey, text, timestamp } from 'drizzle-orm/pg-core';
+import type { AdapterAccountType } from 'next-auth/adapters';
+
+export const users = pgTable('users', {
+  id: text('id')
+    .primaryKey()
+    .$defaultFn(() => crypto.randomUUID()),
+  name: text('name'),
+  email: text('email').unique(),
+  emailVerified: timestamp('email_verified', { mode: 'date' }),
+  image: text('image'),
+});
+
+export const accounts = pgTable(
+  'accounts',
+  {
+    userId: text('user_id')
+      .notNull()
+      .references(() => users.id, { onDelete: 'cascade' }),
+    type: text('type').$type<AdapterAccountType>().notNull(),
+    provider: text('provider').notNull(),
+    providerAccountId: text('provider_account_id').notNull(),
+    refresh_token: text('refresh_token'),
+    access_token: text('access_token'),
+    expires_at: integer('expires_at'),
+    token_type: text('token_type'),
+    scope: text('scope'),
+    id_token: text('id_token'),
+    session_state: text('session_state'),
+  },
+  (account) => [primaryKey({ columns: [account.provider, account.providerAccountId] })],
+);
+
+export const sessions = pgTable('sessions', {
+  sessionToken: text('session_token').primaryKey(),
+  userId: text('user_id')
+    .notNull()
+    .references(() => users.id, { onDelete: 'cascade' }),
+  expires: timestamp('expires', { mode: 'date' }).notNull(),
+});
+
+export const verificationTokens = pgTable(
+  'verification_tokens',
+  {
+    identifier: text('identifier').notNull(),
+    token: text('token').notNull(),
+    expires: timestamp('expires', { mode: 'date' }).notNull(),
+  },
+  (token) => [primaryKey({ columns: [token.identifier, token.token] })],
+);
+"#;
+
+const SQLITE_SCHEMA: &str = r#"import { integer, primaryKey, sqliteTable, text } from 'drizzle-orm/sqlite-core';
+import type { AdapterAccountType } from 'next-auth/adapters';
+
+export const users = sqliteTable('users', {
+  id: text('id')
+    .primaryKey()
+    .$defaultFn(() => crypto.randomUUID()),
+  name: text('name'),
+  email: text('email').unique(),
+  emailVerified: integer('email_verified', { mode: 'timestamp_ms' }),
+  image: text('image'),
+});
+
+export const accounts = sqliteTable(
+  'accounts',
+  {
+    userId: text('user_id')
+      .notNull()
+      .references(() => users.id, { onDelete: 'cascade' }),
+    type: text('type').$type<AdapterAccountType>().notNull(),
+    provider: text('provider').notNull(),
+    providerAccountId: text('provider_account_id').notNull(),
+    refresh_token: text('refresh_token'),
+    access_token: text('access_token'),
+    expires_at: integer('expires_at'),
+    token_type: text('token_type'),
+    scope: text('scope'),
+    id_token: text('id_token'),
+    session_state: text('session_state'),
+  },
+  (account) => [primaryKey({ columns: [account.provider, account.providerAccountId] })],
+);
+
+export const sessions = sqliteTable('sessions', {
+  sessionToken: text('session_token').primaryKey(),
+  userId: text('user_id')
+    .notNull()
+    .references(() => users.id, { onDelete: 'cascade' }),
+  expires: integer('expires', { mode: 'timestamp_ms' }).notNull(),
+});
+
+export const verificationTokens = sqliteTable(
+  'verification_tokens',
+  {
+    identifier: text('identifier').notNull(),
+    token: text('token').notNull(),
+    expires: integer('expires', { mode: 'timestamp_ms' }).notNull(),
+  },
+  (token) => [primaryKey({ columns: [token.identifier, token.token] })],
+);
+"#;
+
+const MYSQL_SCHEMA: &str = r#"import { int, mysqlTable, primaryKey, timestamp, varchar } from 'drizzle-orm/mysql-core';
+import type { AdapterAccountType } from 'next-auth/adapters';
+
+export const users = mysqlTable('users', {
+  id: varchar('id', { length: 255 })
+    .primaryKey()
+    .$defaultFn(() => crypto.randomUUID()),
+  name: varchar('name', { length: 255 }),
+  email: varchar('email', { length: 255 }).unique(),
+  emailVerified: timestamp('email_verified', { mode: 'date', fsp: 3 }),
+  image: varchar('image', { length: 255 }),
+});
+
+export const accounts = mysqlTable(
+  'accounts',
+  {
+    userId: varchar('user_id', { length: 255 })
+      .notNull()
+      .references(() => users.id, { onDelete: 'cascade' }),
+    type: varchar('type', { length: 255 }).$type<AdapterAccountType>().notNull(),
+    provider: varchar('provider', { length: 255 }).notNull(),
+    providerAccountId: varchar('provider_account_id', { length: 255 }).notNull(),
+    refresh_token: varchar('refresh_token', { length: 2048 }),
+    access_token: varchar('access_token', { length: 2048 }),
+    expires_at: int('expires_at'),
+    token_type: varchar('token_type', { length: 255 }),
+    scope: varchar('scope', { length: 255 }),
+    id_token: varchar('id_token', { length: 2048 }),
+    session_state: varchar('session_state', { length: 255 }),
+  },
+  (account) => [primaryKey({ columns: [account.provider, account.providerAccountId] })],
+);
+
+export const sessions = mysqlTable('sessions', {
+  sessionToken: varchar('session_token', { length: 255 }).primaryKey(),
+  userId: varchar('user_id', { length: 255 })
+    .notNull()
+    .references(() => users.id, { onDelete: 'cascade' }),
+  expires: timestamp('expires', { mode: 'date' }).notNull(),
+});
+
+export const verificationTokens = mysqlTable(
+  'verification_tokens',
+  {
+    identifier: varchar('identifier', { length: 255 }).notNull(),
+    token: varchar('token', { length: 255 }).notNull(),
+    expires: timestamp('expires', { mode: 'date' }).notNull(),
+  },
+  (token) => [primaryKey({ columns: [token.identifier, token.token] })],
+);
+"#;
+
+/// `db/schema.ts` with the tables the Auth.js Drizzle adapter reads and
+/// writes, in the column types of the provider's dialect.
+pub fn drizzle_schema(provider: &SqlProvider) -> &'static str {
+    match provider {
+        SqlProvider::Neon | SqlProvider::Postgres => PG_SCHEMA,
+        SqlProvider::Turso | SqlProvider::Sqlite => SQLITE_SCHEMA,
+        SqlProvider::PlanetScale => MYSQL_SCHEMA,
+    }
+}
+
+pub fn add_dependencies(
+    config: &ProjectConfig,
+    deps: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    deps.insert("next-auth".to_string(), json!("beta"));
+    if matches!(config.database, Database::Sql(Orm::Drizzle, _)) {
+        deps.insert("@auth/drizzle-adapter".to_string(), json!("latest"));
+    }
+}
+
+/// `KEY=value` lines with a freshly generated `AUTH_SECRET` and the GitHub
+/// OAuth app credentials.
+pub fn env_vars() -> Result<Vec<String>> {
+    Ok(vec![
+        format!("AUTH_SECRET={}", secret::generate_secret()?),
+        "# GitHub OAuth app: https://github.com/settings/developers".to_string(),
+        "AUTH_GITHUB_ID=".to_string(),
+        "AUTH_GITHUB_SECRET=".to_string(),
+    ])
+}
+
+/// Writes the shared config, the `auth()` helpers, the route handler and
+/// the `/dashboard` page the config protects.
+/// `auth.config.ts` holds everything that can run in middleware; the
+/// database adapter is only added in `auth.ts`.
+pub fn generate_authjs_files(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    let auth_config = r#"import type { NextAuthConfig } from 'next-auth'
+import Credentials from 'next-auth/providers/credentials'
+import GitHub from 'next-auth/providers/github'
+
+export default {
+  providers: [
+    // Reads AUTH_GITHUB_ID and AUTH_GITHUB_SECRET
+    GitHub,
+    Credentials({
+      credentials: {
+        email: { label: 'Email', type: 'email' },
+        password: { label: 'Password', type: 'password' },
+      },
+      // Example only: look the user up and verify a password hash instead
+      authorize: async (credentials) => {
+        if (credentials.email === 'demo@example.com' && credentials.password === 'demo') {
+          return { id: 'demo', name: 'Demo User', email: 'demo@example.com' }
+        }
+        return null
+      },
+    }),
+  ],
+  callbacks: {
+    // Checked by middleware.ts; signed-out visitors to /dashboard are sent to sign in
+    authorized: async ({ auth, request }) => {
+      if (request.nextUrl.pathname.startsWith('/dashboard')) {
+        return !!auth
+      }
+      return true
+    },
+  },
+} satisfies NextAuthConfig
+"#;
+    fs::write_file(&project_path.join("auth.config.ts"), auth_config)?;
+
+    let auth = if matches!(config.database, Database::Sql(Orm::Drizzle, _)) {
+        r#"import { DrizzleAdapter } from '@auth/drizzle-adapter'
+import NextAuth from 'next-auth'
+import authConfig from './auth.config'
+import { db } from './db'
+import { accounts, sessions, users, verificationTokens } from './db/schema'
+
+export const { handlers, auth, signIn, signOut } = NextAuth({
+  adapter: DrizzleAdapter(db, {
+    usersTable: users,
+    accountsTable: accounts,
+    sessionsTable: sessions,
+    verificationTokensTable: verificationTokens,
+  }),
+  // Credentials sign-ins can't create database sessions
+  session: { strategy: 'jwt' },
+  ...authConfig,
+})
+"#
+    } else {
+        r#"import NextAuth from 'next-auth'
+import authConfig from './auth.config'
+
+export const { handlers, auth, signIn, signOut } = NextAuth(authConfig)
+"#
+    };
+    fs::write_file(&project_path.join("auth.ts"), auth)?;
+
+    let route = r#"import { handlers } from '@/auth'
+
+export const { GET, POST } = handlers
+"#;
+    fs::write_file(
+        &project_path.join("app/api/auth/[...nextauth]/route.ts"),
+        route,
+    )?;
+
+    // The page auth.config.ts protects
+    let dashboard = r#"import { auth, signOut } from '@/auth'
+
+export default async function DashboardPage() {
+  const session = await auth()
+
+  return (
+    <main>
+      <h1>Dashboard</h1>
+      <p>Signed in as {session?.user?.name ?? session?.user?.email}</p>
+      <form
+        action={async () => {
+          'use server'
+          await signOut({ redirectTo: '/' })
+        }}
+      >
+        <button type="submit">Sign out</button>
+      </form>
+    </main>
+  )
+}
+"#;
+    fs::write_file(&project_path.join("app/dashboard/page.tsx"), dashboard)?;
+
+    Ok(())
+}
@@ -0,0 +1,455 @@
+use crate::config::{Database, Framework, Orm, ProjectConfig, SqlProvider};
+use crate::utils::{fs, secret};
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+const PG_SCHEMA: &str = r#"import { boolean, pgTable, text, timestamp } from 'drizzle-orm/pg-core';
+
+export const users = pgTable('users', {
+  id: text('id')
+    .primaryKey()
+    .$defaultFn(() => crypto.randomUUID()),
+  name: text('name').notNull(),
+  email: text('email').notNull().unique(),
+  emailVerified: boolean('email_verified').notNull().default(false),
+  image: text('image'),
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+  updatedAt: timestamp('updated_at').notNull().defaultNow(),
+});
+
+export const sessions = pgTable('sessions', {
+  id: text('id').primaryKey(),
+  expiresAt: timestamp('expires_at').notNull(),
+  token: text('token').notNull().unique(),
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+  updatedAt: timestamp('updated_at').notNull().defaultNow(),
+  ipAddress: text('ip_address'),
+  userAgent: text('user_agent'),
+  userId: text('user_id')
+    .notNull()
+    .references(() => users.id, { onDelete: 'cascade' }),
+});
+
+export const accounts = pgTable('accounts', {
+  id: text('id').primaryKey(),
+  accountId: text('account_id').notNull(),
+  providerId: text('provider_id').notNull(),
+  userId: text('user_id')
+    .notNull()
+    .references(() => users.id, { onDelete: 'cascade' }),
+  accessToken: text('access_token'),
+  refreshToken: text('refresh_token'),
+  idToken: text('id_token'),
+  accessTokenExpiresAt: timestamp('access_token_expires_at'),
+  refreshTokenExpiresAt: timestamp('refresh_token_expires_at'),
+  scope: text('scope'),
+  password: text('password'),
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+  updatedAt: timestamp('updated_at').notNull().defaultNow(),
+});
+
+export const verifications = pgTable('verifications', {
+  id: text('id').primaryKey(),
+  identifier: text('identifier').notNull(),
+  value: text('value').notNull(),
+  expiresAt: timestamp('expires_at').notNull(),
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+  updatedAt: timestamp('updated_at').notNull().defaultNow(),
+});
+"#;
+
+const SQLITE_SCHEMA: &str = r#"import { sql } from 'drizzle-orm';
+import { integer, sqliteTable, text } from 'drizzle-orm/sqlite-core';
+
+export const users = sqliteTable('users', {
+  id: text('id')
+    .primaryKey()
+    .$defaultFn(() => crypto.randomUUID()),
+  name: text('name').notNull(),
+  email: text('email').notNull().unique(),
+  emailVerified: integer('email_verified', { mode: 'boolean' }).notNull().default(false),
+  image: text('image'),
+  createdAt: integer('created_at', { mode: 'timestamp' }).notNull().default(sql`(unixepoch())`),
+  updatedAt: integer('updated_at', { mode: 'timestamp' }).notNull().default(sql`(unixepoch())`),
+});
+
+export const sessions = sqliteTable('sessions', {
+  id: text('id').primaryKey(),
+  expiresAt: integer('expires_at', { mode: 'timestamp' }).notNull(),
+  token: text('token').notNull().unique(),
+  createdAt: integer('created_at', { mode: 'timestamp' }).notNull().default(sql`(unixepoch())`),
+  updatedAt: integer('updated_at', { mode: 'timestamp' }).notNull().default(sql`(unixepoch())`),
+  ipAddress: text('ip_address'),
+  userAgent: text('user_agent'),
+  userId: text('user_id')
+    .notNull()
+    .references(() => users.id, { onDelete: 'cascade' }),
+});
+
+export const accounts = sqliteTable('accounts', {
+  id: text('id').primaryKey(),
+  accountId: text('account_id').notNull(),
+  providerId: text('provider_id').notNull(),
+  userId: text('user_id')
+    .notNull()
+    .references(() => users.id, { onDelete: 'cascade' }),
+  accessToken: text('access_token'),
+  refreshToken: text('refresh_token'),
+  idToken: text('id_token'),
+  accessTokenExpiresAt: integer('access_token_expires_at', { mode: 'timestamp' }),
+  refreshTokenExpiresAt: integer('refresh_token_expires_at', { mode: 'timestamp' }),
+  scope: text('scope'),
+  password: text('password'),
+  createdAt: integer('created_at', { mode: 'timestamp' }).notNull().default(sql`(unixepoch())`),
+  updatedAt: integer('updated_at', { mode: 'timestamp' }).notNull().default(sql`(unixepoch())`),
+});
+
+export const verifications = sqliteTable('verifications', {
+  id: text('id').primaryKey(),
+  identifier: text('identifier').notNull(),
+  value: text('value').notNull(),
+  expiresAt: integer('expires_at', { mode: 'timestamp' }).notNull(),
+  createdAt: integer('created_at', { mode: 'timestamp' }).notNull().default(sql`(unixepoch())`),
+  updatedAt: integer('updated_at', { mode: 'timestamp' }).notNull().default(sql`(unixepoch())`),
+});
+"#;
+
+const MYSQL_SCHEMA: &str = r#"import { boolean, mysqlTable, text, timestamp, varchar } from 'drizzle-orm/mysql-core';
+
+export const users = mysqlTable('users', {
+  id: varchar('id', { length: 36 })
+    .primaryKey()
+    .$defaultFn(() => crypto.randomUUID()),
+  name: text('name').notNull(),
+  email: varchar('email', { length: 255 }).notNull().unique(),
+  emailVerified: boolean('email_verified').notNull().default(false),
+  image: text('image'),
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+  updatedAt: timestamp('updated_at').notNull().defaultNow(),
+});
+
+export const sessions = mysqlTable('sessions', {
+  id: varchar('id', { length: 36 }).primaryKey(),
+  expiresAt: timestamp('expires_at').notNull(),
+  token: varchar('token', { length: 255 }).notNull().unique(),
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+  updatedAt: timestamp('updated_at').notNull().defaultNow(),
+  ipAddress: text('ip_address'),
+  userAgent: text('user_agent'),
+  userId: varchar('user_id', { length: 36 })
+    .notNull()
+    .references(() => users.id, { onDelete: 'cascade' }),
+});
+
+export const accounts = mysqlTable('accounts', {
+  id: varchar('id', { length: 36 }).primaryKey(),
+  accountId: text('account_id').notNull(),
+  providerId: text('provider_id').notNull(),
+  userId: varchar('user_id', { length: 36 })
+    .notNull()
+    .references(() => users.id, { onDelete: 'cascade' }),
+  accessToken: text('access_token'),
+  refreshToken: text('refresh_token'),
+  idToken: text('id_token'),
+  accessTokenExpiresAt: timestamp('access_token_expires_at'),
+  refreshTokenExpiresAt: timestamp('refresh_token_expires_at'),
+  scope: text('scope'),
+  password: text('password'),
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+  updatedAt: timestamp('updated_at').notNull().defaultNow(),
+});
+
+export const verifications = mysqlTable('verifications', {
+  id: varchar('id', { length: 36 }).primaryKey(),
+  identifier: text('identifier').notNull(),
+  value: text('value').notNull(),
+  expiresAt: timestamp('expires_at').notNull(),
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+  updatedAt: timestamp('updated_at').notNull().defaultNow(),
+});
+"#;
+
+/// Prisma models Better Auth reads and writes. `{{TEXT}}` marks columns that
+/// outgrow MySQL's default `VARCHAR(191)`.
+const PRISMA_MODELS: &str = r#"model User {
+  id            String    @id @default(uuid())
+  name          String
+  email         String    @unique
+  emailVerified Boolean   @default(false)
+  image         String?
+  createdAt     DateTime  @default(now())
+  updatedAt     DateTime  @updatedAt
+  sessions      Session[]
+  accounts      Account[]
+
+  @@map("users")
+}
+
+model Session {
+  id        String   @id
+  expiresAt DateTime
+  token     String   @unique
+  createdAt DateTime @default(now())
+  updatedAt DateTime @updatedAt
+  ipAddress String?
+  userAgent String?
+  userId    String
+  user      User     @relation(fields: [userId], references: [id], onDelete: Cascade)
+
+  @@index([userId])
+  @@map("sessions")
+}
+
+model Account {
+  id                    String    @id
+  accountId             String
+  providerId            String
+  userId                String
+  user                  User      @relation(fields: [userId], references: [id], onDelete: Cascade)
+  accessToken           String?{{TEXT}}
+  refreshToken          String?{{TEXT}}
+  idToken               String?{{TEXT}}
+  accessTokenExpiresAt  DateTime?
+  refreshTokenExpiresAt DateTime?
+  scope                 String?
+  password              String?
+  createdAt             DateTime  @default(now())
+  updatedAt             DateTime  @updatedAt
+
+  @@index([userId])
+  @@map("accounts")
+}
+
+model Verification {
+  id         String   @id
+  identifier String
+  value      String{{TEXT}}
+  expiresAt  DateTime
+  createdAt  DateTime @default(now())
+  updatedAt  DateTime @updatedAt
+
+  @@map("verifications")
+}
+"#;
+
+/// `db/schema.ts` with the tables Better Auth reads and writes, in the column
+/// types of the provider's dialect.
+pub fn drizzle_schema(provider: &SqlProvider) -> &'static str {
+    match provider {
+        SqlProvider::Neon | SqlProvider::Postgres => PG_SCHEMA,
+        SqlProvider::Turso | SqlProvider::Sqlite => SQLITE_SCHEMA,
+        SqlProvider::PlanetScale => MYSQL_SCHEMA,
+    }
+}
+
+/// The user, session, account and verification models for `schema.prisma`.
+pub fn prisma_models(provider: &SqlProvider) -> String {
+    let text = if matches!(provider, SqlProvider::PlanetScale) {
+        " @db.Text"
+    } else {
+        ""
+    };
+    PRISMA_MODELS.replace("{{TEXT}}", text)
+}
+
+pub fn add_dependencies(
+    config: &ProjectConfig,
+    deps: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    deps.insert("better-auth".to_string(), json!("latest"));
+    if let Database::MongoDB = config.database {
+        deps.insert("mongodb".to_string(), json!("latest"));
+    }
+}
+
+/// `KEY=value` lines with a freshly generated `BETTER_AUTH_SECRET` and the
+/// dev server's URL.
+pub fn env_vars() -> Result<Vec<String>> {
+    Ok(vec![
+        format!("BETTER_AUTH_SECRET={}", secret::generate_secret()?),
+        "BETTER_AUTH_URL=http://localhost:3000".to_string(),
+    ])
+}
+
+/// The `database` option for `betterAuth()`, with the imports it needs.
+fn database_adapter(config: &ProjectConfig) -> (String, String) {
+    let dialect = |provider: &SqlProvider| match provider {
+        SqlProvider::Neon | SqlProvider::Postgres => "pg",
+        SqlProvider::Turso | SqlProvider::Sqlite => "sqlite",
+        SqlProvider::PlanetScale => "mysql",
+    };
+    match &config.database {
+        Database::Sql(Orm::Drizzle, provider) => (
+            "import { drizzleAdapter } from 'better-auth/adapters/drizzle'\nimport { db } from '@/db'\nimport * as schema from '@/db/schema'\n".to_string(),
+            format!(
+                "drizzleAdapter(db, {{ provider: '{}', schema, usePlural: true }})",
+                dialect(provider)
+            ),
+        ),
+        Database::Sql(Orm::Prisma, provider) => {
+            let provider = match dialect(provider) {
+                "pg" => "postgresql",
+                dialect => dialect,
+            };
+            (
+                "import { prismaAdapter } from 'better-auth/adapters/prisma'\nimport { prisma } from '@/lib/prisma'\n".to_string(),
+                format!("prismaAdapter(prisma, {{ provider: '{}' }})", provider),
+            )
+        }
+        Database::MongoDB => (
+            "import { mongodbAdapter } from 'better-auth/adapters/mongodb'\nimport { MongoClient } from 'mongodb'\n\nconst client = new MongoClient(process.env.MONGODB_URI!)\n".to_string(),
+            "mongodbAdapter(client.db())".to_string(),
+        ),
+        database => unreachable!("Better Auth has no adapter for {}", database),
+    }
+}
+
+/// Writes the server and client instances, the API handler and sign-in and
+/// sign-up pages. TanStack Start's routes are defined in code, so its pages
+/// are components that `app.tsx` registers and the handler is the API entry
+/// itself.
+pub fn generate_better_auth_files(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    let is_next = matches!(config.framework, Framework::NextJs);
+    let (adapter_imports, adapter) = database_adapter(config);
+
+    // nextCookies lets server actions set the session cookie
+    let (plugin_import, plugins) = if is_next {
+        (
+            "import { nextCookies } from 'better-auth/next-js'\n",
+            "\n  plugins: [nextCookies()],",
+        )
+    } else {
+        ("", "")
+    };
+    let auth = format!(
+        "import {{ betterAuth }} from 'better-auth'\n{}{}\nexport const auth = betterAuth({{\n  database: {},\n  emailAndPassword: {{\n    enabled: true,\n  }},{}\n}})\n",
+        plugin_import, adapter_imports, adapter, plugins
+    );
+    fs::write_file(&project_path.join("lib/auth.ts"), &auth)?;
+
+    let auth_client = r#"import { createAuthClient } from 'better-auth/react'
+
+export const authClient = createAuthClient()
+
+export const { signIn, signUp, signOut, useSession } = authClient
+"#;
+    fs::write_file(&project_path.join("lib/auth-client.ts"), auth_client)?;
+
+    if is_next {
+        let route = r#"import { toNextJsHandler } from 'better-auth/next-js'
+import { auth } from '@/lib/auth'
+
+export const { GET, POST } = toNextJsHandler(auth.handler)
+"#;
+        fs::write_file(&project_path.join("app/api/auth/[...all]/route.ts"), route)?;
+    } else {
+        let api_entry = r#"import { createStartAPIHandler } from '@tanstack/start/api'
+import { auth } from '@/lib/auth'
+
+export default createStartAPIHandler(({ request }) => {
+  if (new URL(request.url).pathname.startsWith('/api/auth/')) {
+    return auth.handler(request)
+  }
+  return new Response('Not Found', { status: 404 })
+})
+"#;
+        fs::write_file(&project_path.join("app/api.ts"), api_entry)?;
+    }
+
+    let sign_in = r#"{{USE_CLIENT}}import { type FormEvent, useState } from 'react'
+import { authClient } from '@/lib/auth-client'
+
+export default function SignIn() {
+  const [error, setError] = useState<string | null>(null)
+
+  async function onSubmit(event: FormEvent<HTMLFormElement>) {
+    event.preventDefault()
+    const form = new FormData(event.currentTarget)
+    const { error } = await authClient.signIn.email({
+      email: String(form.get('email')),
+      password: String(form.get('password')),
+    })
+    if (error) {
+      setError(error.message ?? 'Sign in failed')
+      return
+    }
+    window.location.href = '/'
+  }
+
+  return (
+    <main>
+      <h1>Sign in</h1>
+      <form onSubmit={onSubmit}>
+        <input name="email" type="email" placeholder="Email" required />
+        <input name="password" type="password" placeholder="Password" required />
+        <button type="submit">Sign in</button>
+      </form>
+      {error && <p role="alert">{error}</p>}
+      <p>
+        No account yet? <a href="/sign-up">Sign up</a>
+      </p>
+    </main>
+  )
+}
+"#;
+
+    let sign_up = r#"{{USE_CLIENT}}import { type FormEvent, useState } from 'react'
+import { authClient } from '@/lib/auth-client'
+
+export default function SignUp() {
+  const [error, setError] = useState<string | null>(null)
+
+  async function onSubmit(event: FormEvent<HTMLFormElement>) {
+    event.preventDefault()
+    const form = new FormData(event.currentTarget)
+    const { error } = await authClient.signUp.email({
+      name: String(form.get('name')),
+      email: String(form.get('email')),
+      password: String(form.get('password')),
+    })
+    if (error) {
+      setError(error.message ?? 'Sign up failed')
+      return
+    }
+    window.location.href = '/'
+  }
+
+  return (
+    <main>
+      <h1>Sign up</h1>
+      <form onSubmit={onSubmit}>
+        <input name="name" placeholder="Name" required />
+        <input name="email" type="email" placeholder="Email" required />
+        <input name="password" type="password" placeholder="Password" minLength={8} required />
+        <button type="submit">Create account</button>
+      </form>
+      {error && <p role="alert">{error}</p>}
+      <p>
+        Already have an account? <a href="/sign-in">Sign in</a>
+      </p>
+    </main>
+  )
+}
+"#;
+
+    let (use_client, sign_in_path, sign_up_path) = if is_next {
+        (
+            "'use client'\n\n",
+            "app/sign-in/page.tsx",
+            "app/sign-up/page.tsx",
+        )
+    } else {
+        ("", "components/sign-in.tsx", "components/sign-up.tsx")
+    };
+    fs::write_file(
+        &project_path.join(sign_in_path),
+        sign_in.replace("{{USE_CLIENT}}", use_client),
+    )?;
+    fs::write_file(
+        &project_path.join(sign_up_path),
+        sign_up.replace("{{USE_CLIENT}}", use_client),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,293 @@
+use crate::config::{CliParser, ProjectConfig};
+use crate::templates::package_json;
+use crate::utils::fs;
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+pub fn generate_cli_project(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    let is_commander = matches!(config.cli_parser, CliParser::Commander);
+    // The command name drops any npm scope from the package name
+    let bin_name = config.name.rsplit('/').next().unwrap_or(&config.name);
+
+    // Generate package.json
+    let mut deps = serde_json::Map::new();
+    if is_commander {
+        deps.insert("commander".to_string(), json!("latest"));
+    } else {
+        deps.insert("citty".to_string(), json!("latest"));
+    }
+
+    let package_json = json!({
+        "name": config.name,
+        "version": "0.1.0",
+        "description": "",
+        "license": "MIT",
+        "type": "module",
+        "bin": {
+            (bin_name): "./dist/index.js"
+        },
+        "files": ["dist"],
+        "engines": {
+            "node": ">=20"
+        },
+        "scripts": {
+            "dev": "tsx src/index.ts",
+            "build": "tsup",
+            "start": "node dist/index.js",
+            "test": "vitest run",
+            "test:watch": "vitest",
+            "typecheck": "tsc --noEmit"
+        },
+        "dependencies": deps,
+        "devDependencies": {
+            "typescript": "latest",
+            "@types/node": "latest",
+            "tsx": "latest",
+            "tsup": "latest",
+            "vitest": "latest"
+        }
+    });
+
+    package_json::write_package_json(project_path, &package_json)?;
+
+    // Generate tsconfig.json
+    let tsconfig = json!({
+        "compilerOptions": {
+            "target": "ES2022",
+            "lib": ["esnext"],
+            "skipLibCheck": true,
+            "strict": true,
+            "noEmit": true,
+            "esModuleInterop": true,
+            "module": "esnext",
+            "moduleResolution": "bundler",
+            "resolveJsonModule": true,
+            "isolatedModules": true,
+            "types": ["node"]
+        },
+        "include": ["src"],
+        "exclude": ["node_modules", "dist"]
+    });
+
+    fs::write_file(
+        &project_path.join("tsconfig.json"),
+        &serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+
+    // Generate tsup.config.ts (tsup keeps the entry's shebang and marks the
+    // output executable)
+    let tsup_config = r#"import { defineConfig } from 'tsup'
+
+export default defineConfig({
+  entry: ['src/index.ts'],
+  format: ['esm'],
+  target: 'node20',
+  clean: true,
+})
+"#;
+    fs::write_file(&project_path.join("tsup.config.ts"), tsup_config)?;
+
+    // Generate vitest.config.ts
+    let vitest_config = r#"import { defineConfig } from 'vitest/config'
+
+export default defineConfig({
+  test: {
+    include: ['src/**/*.test.ts'],
+  },
+})
+"#;
+    fs::write_file(&project_path.join("vitest.config.ts"), vitest_config)?;
+
+    // Generate the command definition, the shebang entrypoint and a test.
+    // The command lives apart from the entrypoint so tests can run it
+    // without spawning a process.
+    let (cli_content, index_content, test_content) = if is_commander {
+        (
+            r#"import { Command } from 'commander'
+
+export function greet(name: string, shout = false): string {
+  const greeting = `Hello, ${name}!`
+  return shout ? greeting.toUpperCase() : greeting
+}
+
+export function createProgram(
+  write: (str: string) => void = (str) => process.stdout.write(str),
+) {
+  const program = new Command()
+
+  program
+    .name('{{PROJECT_NAME}}')
+    .description('A command-line tool generated with cp-cli')
+    .version('0.1.0')
+
+  program
+    .command('greet')
+    .description('Print a greeting')
+    .argument('[name]', 'who to greet', 'world')
+    .option('-s, --shout', 'print the greeting in uppercase')
+    .action((name: string, options: { shout?: boolean }) => {
+      write(`${greet(name, options.shout)}\n`)
+    })
+
+  return program
+}
+"#,
+            r#"#!/usr/bin/env node
+import { createProgram } from './cli'
+
+await createProgram().parseAsync(process.argv)
+"#,
+            r#"import { describe, expect, it } from 'vitest'
+import { createProgram, greet } from './cli'
+
+describe('greet', () => {
+  it('greets by name', () => {
+    expect(greet('world')).toBe('Hello, world!')
+  })
+
+  it('shouts when asked to', () => {
+    expect(greet('world', true)).toBe('HELLO, WORLD!')
+  })
+})
+
+describe('cli', () => {
+  it('prints a greeting', async () => {
+    let output = ''
+    const program = createProgram((str) => (output += str)).exitOverride()
+
+    await program.parseAsync(['greet', 'cp-cli', '--shout'], { from: 'user' })
+
+    expect(output).toBe('HELLO, CP-CLI!\n')
+  })
+})
+"#,
+        )
+    } else {
+        (
+            r#"import { defineCommand } from 'citty'
+
+export function greet(name: string, shout = false): string {
+  const greeting = `Hello, ${name}!`
+  return shout ? greeting.toUpperCase() : greeting
+}
+
+export const greetCommand = defineCommand({
+  meta: {
+    name: 'greet',
+    description: 'Print a greeting',
+  },
+  args: {
+    name: {
+      type: 'positional',
+      description: 'who to greet',
+      required: false,
+      default: 'world',
+    },
+    shout: {
+      type: 'boolean',
+      alias: 's',
+      description: 'print the greeting in uppercase',
+    },
+  },
+  run({ args }) {
+    const greeting = greet(args.name, args.shout)
+    console.log(greeting)
+    return greeting
+  },
+})
+
+export const main = defineCommand({
+  meta: {
+    name: '{{PROJECT_NAME}}',
+    version: '0.1.0',
+    description: 'A command-line tool generated with cp-cli',
+  },
+  subCommands: {
+    greet: greetCommand,
+  },
+})
+"#,
+            r#"#!/usr/bin/env node
+import { runMain } from 'citty'
+import { main } from './cli'
+
+runMain(main)
+"#,
+            r#"import { runCommand } from 'citty'
+import { describe, expect, it } from 'vitest'
+import { greet, greetCommand } from './cli'
+
+describe('greet', () => {
+  it('greets by name', () => {
+    expect(greet('world')).toBe('Hello, world!')
+  })
+
+  it('shouts when asked to', () => {
+    expect(greet('world', true)).toBe('HELLO, WORLD!')
+  })
+})
+
+describe('cli', () => {
+  it('runs the greet command', async () => {
+    const { result } = await runCommand(greetCommand, {
+      rawArgs: ['cp-cli', '--shout'],
+    })
+
+    expect(result).toBe('HELLO, CP-CLI!')
+  })
+})
+"#,
+        )
+    };
+    let cli_content = cli_content.replace("{{PROJECT_NAME}}", bin_name);
+    fs::write_file(&project_path.join("src/cli.ts"), &cli_content)?;
+    fs::write_file(&project_path.join("src/index.ts"), index_content)?;
+    fs::write_file(&project_path.join("src/cli.test.ts"), test_content)?;
+
+    // Generate README.md
+    let pm = &config.package_manager;
+    let readme = format!(
+        "# {}\n\nA command-line tool built with {}, generated with cp-cli.\n\n## Getting started\n\n```bash\n{}\n{}\n```\n\nTo try the built binary globally, run `{}` followed by `npm link`, then `{} greet`.\n\n## Scripts\n\n- `{}` - run the CLI from source with tsx\n- `{}` - bundle to `dist/` with tsup\n- `{}` - run the test suite\n- `{}` - type-check the project\n",
+        config.name,
+        config.cli_parser,
+        pm.install_cmd(),
+        pm.run_cmd("dev greet"),
+        pm.run_cmd("build"),
+        bin_name,
+        pm.run_cmd("dev"),
+        pm.run_cmd("build"),
+        pm.run_cmd("test"),
+        pm.run_cmd("typecheck"),
+    );
+    fs::write_file(&project_path.join("README.md"), &readme)?;
+
+    // Generate .gitignore
+    let gitignore = r#"# dependencies
+/node_modules
+
+# build
+/dist
+
+# testing
+/coverage
+
+# misc
+.DS_Store
+*.pem
+
+# debug
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+
+# local env files
+.env*.local
+
+# typescript
+*.tsbuildinfo
+"#;
+    fs::write_file(&project_path.join(".gitignore"), gitignore)?;
+
+    Ok(())
+}
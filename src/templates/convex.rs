@@ -0,0 +1,92 @@
+use crate::config::{Auth, ProjectConfig};
+use crate::utils::fs;
+use anyhow::Result;
+use std::path::Path;
+
+/// Commands that create the dev deployment and configure its auth.
+pub fn setup_steps(config: &ProjectConfig) -> Vec<String> {
+    let pm = &config.package_manager;
+    // `convex dev --once` writes the deployment URL to `.env.local`
+    let mut steps = vec![pm.exec_cmd("convex dev --once")];
+    if matches!(config.auth, Auth::Clerk) {
+        // Convex functions read their env from the deployment, not `.env.local`
+        steps.push(
+            pm.exec_cmd("convex env set CLERK_JWT_ISSUER_DOMAIN <your Clerk Frontend API URL>"),
+        );
+    }
+    steps
+}
+
+/// Writes the schema and sample functions, plus Clerk's token config when
+/// Clerk is selected.
+pub fn generate_convex_files(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    let schema_content = r#"import { defineSchema, defineTable } from 'convex/server'
+import { v } from 'convex/values'
+
+export default defineSchema({
+  tasks: defineTable({
+    text: v.string(),
+    isCompleted: v.boolean(),
+  }),
+})
+"#;
+    fs::write_file(&project_path.join("convex/schema.ts"), schema_content)?;
+
+    let tasks_content = r#"import { v } from 'convex/values'
+import { mutation, query } from './_generated/server'
+
+export const list = query({
+  args: {},
+  handler: async (ctx) => {
+    return await ctx.db.query('tasks').order('desc').collect()
+  },
+})
+
+export const create = mutation({
+  args: { text: v.string() },
+  handler: async (ctx, args) => {
+    return await ctx.db.insert('tasks', { text: args.text, isCompleted: false })
+  },
+})
+"#;
+    fs::write_file(&project_path.join("convex/tasks.ts"), tasks_content)?;
+
+    if !matches!(config.auth, Auth::Clerk) {
+        return Ok(());
+    }
+
+    // Convex verifies the JWTs Clerk issues from its "convex" JWT template
+    let auth_config = r#"export default {
+  providers: [
+    {
+      // The Frontend API URL of your Clerk instance, e.g. https://verb-noun-00.clerk.accounts.dev
+      domain: process.env.CLERK_JWT_ISSUER_DOMAIN,
+      applicationID: 'convex',
+    },
+  ],
+}
+"#;
+    fs::write_file(&project_path.join("convex/auth.config.ts"), auth_config)?;
+
+    let users_content = r#"import { query } from './_generated/server'
+
+// The signed-in Clerk user, or null when signed out
+export const current = query({
+  args: {},
+  handler: async (ctx) => {
+    const identity = await ctx.auth.getUserIdentity()
+    if (identity === null) {
+      return null
+    }
+    return {
+      id: identity.subject,
+      name: identity.name,
+      email: identity.email,
+    }
+  },
+})
+"#;
+    fs::write_file(&project_path.join("convex/users.ts"), users_content)?;
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use crate::config::{Database, ProjectConfig, Redis};
+use crate::templates::database;
+use crate::utils::fs;
+use anyhow::Result;
+use std::borrow::Cow;
+use std::path::Path;
+
+/// A docker-compose service and the named volume it stores data in.
+struct Service {
+    name: &'static str,
+    definition: Cow<'static, str>,
+    volume: Option<&'static str>,
+}
+
+const MONGO: Service = Service {
+    name: "mongo",
+    definition: Cow::Borrowed(
+        r#"    image: mongo:7
+    restart: unless-stopped
+    ports:
+      - "27017:27017"
+    volumes:
+      - mongo-data:/data/db
+"#,
+    ),
+    volume: Some("mongo-data"),
+};
+
+const REDIS: Service = Service {
+    name: "redis",
+    definition: Cow::Borrowed(
+        r#"    image: redis:7-alpine
+    restart: unless-stopped
+    ports:
+      - "6379:6379"
+    volumes:
+      - redis-data:/data
+"#,
+    ),
+    volume: Some("redis-data"),
+};
+
+/// Postgres with a healthcheck, so `docker compose up --wait` returns once it
+/// accepts connections. Databases after the first are created by an init
+/// script, see [`generate_workspace_compose`].
+fn postgres(databases: &[String]) -> Service {
+    let mut definition = format!(
+        r#"    image: postgres:17-alpine
+    restart: unless-stopped
+    ports:
+      - "5432:5432"
+    environment:
+      POSTGRES_USER: postgres
+      POSTGRES_PASSWORD: postgres
+      POSTGRES_DB: {}
+    healthcheck:
+      test: ["CMD-SHELL", "pg_isready -U postgres"]
+      interval: 5s
+      timeout: 5s
+      retries: 10
+    volumes:
+      - postgres-data:/var/lib/postgresql/data
+"#,
+        databases[0]
+    );
+    if databases.len() > 1 {
+        definition.push_str(&format!(
+            "      - ./{}:/docker-entrypoint-initdb.d/init.sql:ro\n",
+            POSTGRES_INIT
+        ));
+    }
+    Service {
+        name: "postgres",
+        definition: Cow::Owned(definition),
+        volume: Some("postgres-data"),
+    }
+}
+
+/// Token the `redis-rest` service accepts; `.env.local` passes it to the
+/// Upstash client.
+pub const REDIS_REST_TOKEN: &str = "local-token";
+
+/// Upstash's REST API in front of the local Redis, so `@upstash/redis` works
+/// without an Upstash account.
+fn redis_rest() -> Service {
+    let definition = format!(
+        r#"    image: hiett/serverless-redis-http:latest
+    restart: unless-stopped
+    ports:
+      - "8079:80"
+    environment:
+      SRH_MODE: env
+      SRH_TOKEN: {}
+      SRH_CONNECTION_STRING: redis://redis:6379
+    depends_on:
+      - redis
+"#,
+        REDIS_REST_TOKEN
+    );
+    Service {
+        name: "redis-rest",
+        definition: Cow::Owned(definition),
+        volume: None,
+    }
+}
+
+/// Local services the project's config depends on.
+fn services(config: &ProjectConfig) -> Vec<Service> {
+    let mut services = Vec::new();
+    if database::docker_postgres(config) {
+        services.push(postgres(&[database::database_name(config)]));
+    }
+    if let Database::MongoDB = config.database {
+        services.push(MONGO);
+    }
+    match config.redis {
+        Redis::Upstash => {
+            services.push(REDIS);
+            services.push(redis_rest());
+        }
+        Redis::Ioredis => services.push(REDIS),
+        Redis::None => {}
+    }
+    services
+}
+
+/// Whether the project gets a `docker-compose.yml`.
+pub fn has_services(config: &ProjectConfig) -> bool {
+    !services(config).is_empty()
+}
+
+/// Writes `docker-compose.yml` when the project needs local services.
+pub fn generate_docker_compose(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    write_compose(&services(config), project_path)
+}
+
+/// Writes one `docker-compose.yml` at the workspace root with the services of
+/// every app, so their host ports don't collide. A single Postgres server
+/// holds `postgres_databases`.
+pub fn generate_workspace_compose(
+    apps: &[ProjectConfig],
+    postgres_databases: &[String],
+    root_path: &Path,
+) -> Result<()> {
+    let mut merged: Vec<Service> = Vec::new();
+    for service in apps.iter().flat_map(services) {
+        if merged.iter().all(|s| s.name != service.name) {
+            merged.push(service);
+        }
+    }
+    if let Some(service) = merged.iter_mut().find(|s| s.name == "postgres") {
+        *service = postgres(postgres_databases);
+        if postgres_databases.len() > 1 {
+            let mut init = String::new();
+            for database in &postgres_databases[1..] {
+                init.push_str(&format!("CREATE DATABASE \"{}\";\n", database));
+            }
+            fs::write_file(&root_path.join(POSTGRES_INIT), &init)?;
+        }
+    }
+    write_compose(&merged, root_path)
+}
+
+/// Init script creating the extra databases of a shared Postgres server.
+const POSTGRES_INIT: &str = "docker/postgres-init.sql";
+
+fn write_compose(services: &[Service], project_path: &Path) -> Result<()> {
+    if services.is_empty() {
+        return Ok(());
+    }
+
+    let mut content = String::from("services:\n");
+    for service in services {
+        content.push_str(&format!("  {}:\n{}", service.name, service.definition));
+    }
+
+    let volumes: Vec<&str> = services.iter().filter_map(|s| s.volume).collect();
+    if !volumes.is_empty() {
+        content.push_str("\nvolumes:\n");
+        for volume in volumes {
+            content.push_str(&format!("  {}:\n", volume));
+        }
+    }
+
+    fs::write_file(&project_path.join("docker-compose.yml"), &content)
+}
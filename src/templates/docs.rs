@@ -0,0 +1,521 @@
+use crate::config::{Framework, ProjectConfig};
+use crate::templates::package_json;
+use crate::utils::fs;
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+pub fn generate_docs_project(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    match config.framework {
+        Framework::Starlight => generate_starlight(config, project_path)?,
+        _ => generate_fumadocs(config, project_path)?,
+    }
+
+    // Generate README.md
+    let pm = &config.package_manager;
+    let content_dir = match config.framework {
+        Framework::Starlight => "src/content/docs",
+        _ => "content/docs",
+    };
+    let readme = format!(
+        "# {}\n\nA documentation site built with {}, generated with cp-cli.\n\n## Getting started\n\n```bash\n{}\n{}\n```\n\n## Writing docs\n\nPages are MDX files in `{}`. The sidebar follows the folder structure; see the sample pages for how to order and group them.\n\n## Scripts\n\n- `{}` - start the dev server\n- `{}` - build the static site and search index\n",
+        config.name,
+        config.framework,
+        pm.install_cmd(),
+        pm.dev_cmd(),
+        content_dir,
+        pm.run_cmd("dev"),
+        pm.run_cmd("build"),
+    );
+    fs::write_file(&project_path.join("README.md"), &readme)?;
+
+    Ok(())
+}
+
+fn generate_fumadocs(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    // Generate package.json
+    let package_json = json!({
+        "name": config.name,
+        "version": "0.1.0",
+        "private": true,
+        "type": "module",
+        "scripts": {
+            "dev": "next dev",
+            "build": "next build",
+            "start": "next start",
+            "postinstall": "fumadocs-mdx"
+        },
+        "dependencies": {
+            "next": "latest",
+            "react": "latest",
+            "react-dom": "latest",
+            "fumadocs-core": "latest",
+            "fumadocs-mdx": "latest",
+            "fumadocs-ui": "latest"
+        },
+        "devDependencies": {
+            "typescript": "latest",
+            "@types/mdx": "latest",
+            "@types/node": "latest",
+            "@types/react": "latest",
+            "@types/react-dom": "latest",
+            "tailwindcss": "latest",
+            "@tailwindcss/postcss": "latest",
+            "postcss": "latest"
+        }
+    });
+
+    package_json::write_package_json(project_path, &package_json)?;
+
+    // Generate tsconfig.json
+    let tsconfig = json!({
+        "compilerOptions": {
+            "target": "ES2017",
+            "lib": ["dom", "dom.iterable", "esnext"],
+            "allowJs": true,
+            "skipLibCheck": true,
+            "strict": true,
+            "noEmit": true,
+            "esModuleInterop": true,
+            "module": "esnext",
+            "moduleResolution": "bundler",
+            "resolveJsonModule": true,
+            "isolatedModules": true,
+            "jsx": "preserve",
+            "incremental": true,
+            "plugins": [
+                {
+                    "name": "next"
+                }
+            ],
+            "paths": {
+                "@/*": ["./*"],
+                "@/.source": ["./.source/index.ts"]
+            }
+        },
+        "include": ["next-env.d.ts", "**/*.ts", "**/*.tsx", ".next/types/**/*.ts"],
+        "exclude": ["node_modules"]
+    });
+
+    fs::write_file(
+        &project_path.join("tsconfig.json"),
+        &serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+
+    // Generate next.config.mjs and source.config.ts
+    let next_config = r#"import { createMDX } from 'fumadocs-mdx/next'
+
+const withMDX = createMDX()
+
+/** @type {import('next').NextConfig} */
+const config = {
+  reactStrictMode: true,
+}
+
+export default withMDX(config)
+"#;
+    fs::write_file(&project_path.join("next.config.mjs"), next_config)?;
+
+    let source_config = r#"import { defineConfig, defineDocs } from 'fumadocs-mdx/config'
+
+export const docs = defineDocs({
+  dir: 'content/docs',
+})
+
+export default defineConfig()
+"#;
+    fs::write_file(&project_path.join("source.config.ts"), source_config)?;
+
+    let postcss_config = r#"export default {
+  plugins: {
+    '@tailwindcss/postcss': {},
+  },
+}
+"#;
+    fs::write_file(&project_path.join("postcss.config.mjs"), postcss_config)?;
+
+    // Generate lib/source.ts
+    let source = r#"import { docs } from '@/.source'
+import { loader } from 'fumadocs-core/source'
+
+export const source = loader({
+  baseUrl: '/docs',
+  source: docs.toFumadocsSource(),
+})
+"#;
+    fs::write_file(&project_path.join("lib/source.ts"), source)?;
+
+    let mdx_components = r#"import defaultMdxComponents from 'fumadocs-ui/mdx'
+import type { MDXComponents } from 'mdx/types'
+
+export function getMDXComponents(components?: MDXComponents): MDXComponents {
+  return {
+    ...defaultMdxComponents,
+    ...components,
+  }
+}
+"#;
+    fs::write_file(&project_path.join("mdx-components.tsx"), mdx_components)?;
+
+    // Generate app directory structure
+    let layout_content = r#"import { RootProvider } from 'fumadocs-ui/provider'
+import type { ReactNode } from 'react'
+import './global.css'
+
+export const metadata = {
+  title: {
+    template: '%s | {{PROJECT_NAME}}',
+    default: '{{PROJECT_NAME}}',
+  },
+  description: 'Generated with cp-cli',
+}
+
+export default function RootLayout({ children }: { children: ReactNode }) {
+  return (
+    <html lang="en" suppressHydrationWarning>
+      <body className="flex min-h-screen flex-col">
+        <RootProvider>{children}</RootProvider>
+      </body>
+    </html>
+  )
+}
+"#;
+    let layout_content = layout_content.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(&project_path.join("app/layout.tsx"), &layout_content)?;
+
+    let global_css = r#"@import 'tailwindcss';
+@import 'fumadocs-ui/css/neutral.css';
+@import 'fumadocs-ui/css/preset.css';
+"#;
+    fs::write_file(&project_path.join("app/global.css"), global_css)?;
+
+    let layout_config = r#"import type { BaseLayoutProps } from 'fumadocs-ui/layouts/shared'
+
+export const baseOptions: BaseLayoutProps = {
+  nav: {
+    title: '{{PROJECT_NAME}}',
+  },
+  links: [
+    {
+      text: 'Documentation',
+      url: '/docs',
+      active: 'nested-url',
+    },
+  ],
+}
+"#;
+    let layout_config = layout_config.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(&project_path.join("app/layout.config.tsx"), &layout_config)?;
+
+    let home_layout = r#"import { HomeLayout } from 'fumadocs-ui/layouts/home'
+import type { ReactNode } from 'react'
+import { baseOptions } from '@/app/layout.config'
+
+export default function Layout({ children }: { children: ReactNode }) {
+  return <HomeLayout {...baseOptions}>{children}</HomeLayout>
+}
+"#;
+    fs::write_file(&project_path.join("app/(home)/layout.tsx"), home_layout)?;
+
+    let home_page = r#"import Link from 'next/link'
+
+export default function HomePage() {
+  return (
+    <main className="flex flex-1 flex-col items-center justify-center text-center">
+      <h1 className="mb-4 text-3xl font-bold">{{PROJECT_NAME}}</h1>
+      <p className="text-fd-muted-foreground">
+        Read the{' '}
+        <Link href="/docs" className="font-semibold underline">
+          documentation
+        </Link>
+        .
+      </p>
+    </main>
+  )
+}
+"#;
+    let home_page = home_page.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(&project_path.join("app/(home)/page.tsx"), &home_page)?;
+
+    let docs_layout = r#"import { DocsLayout } from 'fumadocs-ui/layouts/docs'
+import type { ReactNode } from 'react'
+import { baseOptions } from '@/app/layout.config'
+import { source } from '@/lib/source'
+
+export default function Layout({ children }: { children: ReactNode }) {
+  return (
+    <DocsLayout tree={source.pageTree} {...baseOptions}>
+      {children}
+    </DocsLayout>
+  )
+}
+"#;
+    fs::write_file(&project_path.join("app/docs/layout.tsx"), docs_layout)?;
+
+    let docs_page = r#"import { source } from '@/lib/source'
+import {
+  DocsBody,
+  DocsDescription,
+  DocsPage,
+  DocsTitle,
+} from 'fumadocs-ui/page'
+import { notFound } from 'next/navigation'
+import { getMDXComponents } from '@/mdx-components'
+
+type Props = {
+  params: Promise<{ slug?: string[] }>
+}
+
+export default async function Page(props: Props) {
+  const params = await props.params
+  const page = source.getPage(params.slug)
+  if (!page) notFound()
+
+  const MDX = page.data.body
+
+  return (
+    <DocsPage toc={page.data.toc} full={page.data.full}>
+      <DocsTitle>{page.data.title}</DocsTitle>
+      <DocsDescription>{page.data.description}</DocsDescription>
+      <DocsBody>
+        <MDX components={getMDXComponents()} />
+      </DocsBody>
+    </DocsPage>
+  )
+}
+
+export async function generateStaticParams() {
+  return source.generateParams()
+}
+
+export async function generateMetadata(props: Props) {
+  const params = await props.params
+  const page = source.getPage(params.slug)
+  if (!page) notFound()
+
+  return {
+    title: page.data.title,
+    description: page.data.description,
+  }
+}
+"#;
+    fs::write_file(
+        &project_path.join("app/docs/[[...slug]]/page.tsx"),
+        docs_page,
+    )?;
+
+    // Search is served by Fumadocs' built-in Orama index
+    let search_route = r#"import { source } from '@/lib/source'
+import { createFromSource } from 'fumadocs-core/search/server'
+
+export const { GET } = createFromSource(source)
+"#;
+    fs::write_file(&project_path.join("app/api/search/route.ts"), search_route)?;
+
+    // Generate sample content and sidebar ordering
+    let content_path = project_path.join("content/docs");
+    let root_meta = json!({
+        "title": config.name,
+        "pages": ["index", "getting-started", "---Guides---", "...guides"]
+    });
+    fs::write_file(
+        &content_path.join("meta.json"),
+        &serde_json::to_string_pretty(&root_meta)?,
+    )?;
+    let guides_meta = json!({
+        "title": "Guides",
+        "pages": ["configuration"]
+    });
+    fs::write_file(
+        &content_path.join("guides/meta.json"),
+        &serde_json::to_string_pretty(&guides_meta)?,
+    )?;
+
+    write_sample_pages(config, &content_path, "/docs")?;
+
+    // Generate .gitignore
+    let gitignore = r#"# dependencies
+/node_modules
+
+# generated content
+.source
+
+# next.js
+/.next/
+/out/
+
+# misc
+.DS_Store
+*.pem
+
+# debug
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+
+# local env files
+.env*.local
+
+# typescript
+*.tsbuildinfo
+next-env.d.ts
+"#;
+    fs::write_file(&project_path.join(".gitignore"), gitignore)?;
+
+    Ok(())
+}
+
+fn generate_starlight(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    // Generate package.json
+    let package_json = json!({
+        "name": config.name,
+        "version": "0.1.0",
+        "private": true,
+        "type": "module",
+        "scripts": {
+            "dev": "astro dev",
+            "build": "astro build",
+            "preview": "astro preview",
+            "astro": "astro"
+        },
+        "dependencies": {
+            "astro": "latest",
+            "@astrojs/starlight": "latest",
+            "sharp": "latest"
+        },
+        "devDependencies": {
+            "typescript": "latest"
+        }
+    });
+
+    package_json::write_package_json(project_path, &package_json)?;
+
+    // Generate tsconfig.json
+    let tsconfig = json!({
+        "extends": "astro/tsconfigs/strict",
+        "include": [".astro/types.d.ts", "**/*"],
+        "exclude": ["dist"]
+    });
+
+    fs::write_file(
+        &project_path.join("tsconfig.json"),
+        &serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+
+    // Generate astro.config.mjs with the sidebar; Starlight builds a
+    // Pagefind search index on `astro build`
+    let astro_config = r#"// @ts-check
+import { defineConfig } from 'astro/config'
+import starlight from '@astrojs/starlight'
+
+export default defineConfig({
+  integrations: [
+    starlight({
+      title: '{{PROJECT_NAME}}',
+      sidebar: [
+        {
+          label: 'Start here',
+          items: [{ label: 'Getting started', slug: 'getting-started' }],
+        },
+        {
+          label: 'Guides',
+          autogenerate: { directory: 'guides' },
+        },
+      ],
+    }),
+  ],
+})
+"#;
+    let astro_config = astro_config.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(&project_path.join("astro.config.mjs"), &astro_config)?;
+
+    let content_config = r#"import { defineCollection } from 'astro:content'
+import { docsLoader } from '@astrojs/starlight/loaders'
+import { docsSchema } from '@astrojs/starlight/schema'
+
+export const collections = {
+  docs: defineCollection({ loader: docsLoader(), schema: docsSchema() }),
+}
+"#;
+    fs::write_file(&project_path.join("src/content.config.ts"), content_config)?;
+
+    // Generate sample content
+    let content_path = project_path.join("src/content/docs");
+    write_sample_pages(config, &content_path, "")?;
+
+    // Generate .gitignore
+    let gitignore = r#"# dependencies
+/node_modules
+
+# build output
+/dist
+.astro/
+
+# misc
+.DS_Store
+*.pem
+
+# debug
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+
+# local env files
+.env*.local
+"#;
+    fs::write_file(&project_path.join(".gitignore"), gitignore)?;
+
+    Ok(())
+}
+
+fn write_sample_pages(config: &ProjectConfig, content_path: &Path, base_url: &str) -> Result<()> {
+    let index = r#"---
+title: {{PROJECT_NAME}}
+description: Documentation for {{PROJECT_NAME}}.
+---
+
+Welcome to the {{PROJECT_NAME}} documentation.
+
+Start with [Getting started]({{BASE_URL}}/getting-started), then browse the guides in the sidebar.
+"#;
+    let index = index
+        .replace("{{PROJECT_NAME}}", &config.name)
+        .replace("{{BASE_URL}}", base_url);
+    fs::write_file(&content_path.join("index.mdx"), &index)?;
+
+    let getting_started = r#"---
+title: Getting started
+description: Install and run {{PROJECT_NAME}}.
+---
+
+## Installation
+
+```bash
+npm install {{PROJECT_NAME}}
+```
+
+## Next steps
+
+Read the [configuration guide]({{BASE_URL}}/guides/configuration).
+"#;
+    let getting_started = getting_started
+        .replace("{{PROJECT_NAME}}", &config.name)
+        .replace("{{BASE_URL}}", base_url);
+    fs::write_file(&content_path.join("getting-started.mdx"), &getting_started)?;
+
+    let configuration = r#"---
+title: Configuration
+description: Configure {{PROJECT_NAME}} for your project.
+---
+
+Describe the available options here. Every MDX file in this folder shows
+up under **Guides** in the sidebar.
+"#;
+    let configuration = configuration.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(
+        &content_path.join("guides/configuration.mdx"),
+        &configuration,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,203 @@
+use crate::config::{Auth, ProjectConfig, SqlProvider};
+use crate::templates::{authjs, better_auth, database};
+use crate::utils::fs;
+use anyhow::Result;
+use serde_json::{Value, json};
+use std::path::Path;
+
+const PG_SCHEMA: &str = r#"import { pgTable, serial, text, timestamp } from 'drizzle-orm/pg-core';
+
+export const users = pgTable('users', {
+  id: serial('id').primaryKey(),
+  name: text('name').notNull(),
+  email: text('email').notNull(),
+  createdAt: timestamp('created_at').defaultNow(),
+});
+"#;
+
+const SQLITE_SCHEMA: &str = r#"import { sql } from 'drizzle-orm';
+import { integer, sqliteTable, text } from 'drizzle-orm/sqlite-core';
+
+export const users = sqliteTable('users', {
+  id: integer('id').primaryKey({ autoIncrement: true }),
+  name: text('name').notNull(),
+  email: text('email').notNull(),
+  createdAt: integer('created_at', { mode: 'timestamp' }).default(sql`(unixepoch())`),
+});
+"#;
+
+const MYSQL_SCHEMA: &str = r#"import { mysqlTable, serial, text, timestamp, varchar } from 'drizzle-orm/mysql-core';
+
+export const users = mysqlTable('users', {
+  id: serial('id').primaryKey(),
+  name: text('name').notNull(),
+  email: varchar('email', { length: 255 }).notNull(),
+  createdAt: timestamp('created_at').defaultNow(),
+});
+"#;
+
+/// `db/schema.ts` in the column types of the provider's dialect. With an auth
+/// library the sample table is replaced by the tables its adapter needs.
+pub fn schema(config: &ProjectConfig, provider: &SqlProvider) -> &'static str {
+    match config.auth {
+        Auth::NextAuth => return authjs::drizzle_schema(provider),
+        Auth::BetterAuth => return better_auth::drizzle_schema(provider),
+        Auth::Clerk | Auth::None => {}
+    }
+    match provider {
+        SqlProvider::Neon | SqlProvider::Postgres => PG_SCHEMA,
+        SqlProvider::Turso | SqlProvider::Sqlite => SQLITE_SCHEMA,
+        SqlProvider::PlanetScale => MYSQL_SCHEMA,
+    }
+}
+
+/// `db/index.ts`, exporting a `db` bound to the provider's driver.
+pub fn client(provider: &SqlProvider) -> &'static str {
+    match provider {
+        SqlProvider::Neon => {
+            r#"import { neon } from '@neondatabase/serverless';
+import { drizzle } from 'drizzle-orm/neon-http';
+import * as schema from './schema';
+
+const sql = neon(process.env.DATABASE_URL!);
+
+export const db = drizzle(sql, { schema });
+"#
+        }
+        SqlProvider::Postgres => {
+            r#"import { drizzle } from 'drizzle-orm/node-postgres';
+import { Pool } from 'pg';
+import * as schema from './schema';
+
+const pool = new Pool({ connectionString: process.env.DATABASE_URL });
+
+export const db = drizzle(pool, { schema });
+"#
+        }
+        SqlProvider::Turso => {
+            r#"import { createClient } from '@libsql/client';
+import { drizzle } from 'drizzle-orm/libsql';
+import * as schema from './schema';
+
+const client = createClient({
+  url: process.env.DATABASE_URL!,
+  authToken: process.env.DATABASE_AUTH_TOKEN,
+});
+
+export const db = drizzle(client, { schema });
+"#
+        }
+        SqlProvider::Sqlite => {
+            r#"import Database from 'better-sqlite3';
+import { drizzle } from 'drizzle-orm/better-sqlite3';
+import * as schema from './schema';
+
+const sqlite = new Database(process.env.DATABASE_URL ?? 'local.db');
+
+export const db = drizzle(sqlite, { schema });
+"#
+        }
+        SqlProvider::PlanetScale => {
+            r#"import { Client } from '@planetscale/database';
+import { drizzle } from 'drizzle-orm/planetscale-serverless';
+import * as schema from './schema';
+
+const client = new Client({ url: process.env.DATABASE_URL });
+
+export const db = drizzle(client, { schema, mode: 'planetscale' });
+"#
+        }
+    }
+}
+
+/// Inserts a few rows through the project's `db` client.
+pub const SEED: &str = r#"import { db } from './index';
+import { users } from './schema';
+
+async function main() {
+  await db.insert(users).values([
+    { name: 'Ada Lovelace', email: 'ada@example.com' },
+    { name: 'Grace Hopper', email: 'grace@example.com' },
+  ]);
+  console.log('Seeded users');
+}
+
+main().catch((err) => {
+  console.error(err);
+  process.exit(1);
+});
+"#;
+
+/// `drizzle.config.ts` for drizzle-kit.
+pub fn config(provider: &SqlProvider, schema_path: &str) -> String {
+    let dialect = match provider {
+        SqlProvider::Neon | SqlProvider::Postgres => "postgresql",
+        SqlProvider::Turso => "turso",
+        SqlProvider::Sqlite => "sqlite",
+        SqlProvider::PlanetScale => "mysql",
+    };
+    let auth_token = if matches!(provider, SqlProvider::Turso) {
+        "\n    authToken: process.env.DATABASE_AUTH_TOKEN,"
+    } else {
+        ""
+    };
+
+    format!(
+        r#"import {{ defineConfig }} from 'drizzle-kit';
+
+export default defineConfig({{
+  schema: '{}',
+  out: './drizzle',
+  dialect: '{}',
+  dbCredentials: {{
+    url: process.env.DATABASE_URL!,{}
+  }},
+}});
+"#,
+        schema_path, dialect, auth_token
+    )
+}
+
+pub fn add_dependencies(
+    provider: &SqlProvider,
+    deps: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    deps.insert("drizzle-orm".to_string(), json!("latest"));
+    deps.insert("drizzle-kit".to_string(), json!("latest"));
+    for package in database::driver_packages(provider) {
+        deps.insert(package.to_string(), json!("latest"));
+    }
+}
+
+/// Adds drizzle-kit migration scripts and a seed script.
+pub fn add_scripts(seed_path: &str, package_json: &mut Value) {
+    // drizzle-kit doesn't load `.env.local` on its own
+    package_json["devDependencies"]["dotenv-cli"] = json!("latest");
+    package_json["devDependencies"]["tsx"] = json!("latest");
+    let scripts = &mut package_json["scripts"];
+    scripts["db:generate"] = json!("dotenv -e .env.local -- drizzle-kit generate");
+    scripts["db:migrate"] = json!("dotenv -e .env.local -- drizzle-kit migrate");
+    scripts["db:push"] = json!("dotenv -e .env.local -- drizzle-kit push");
+    scripts["db:studio"] = json!("dotenv -e .env.local -- drizzle-kit studio");
+    scripts["db:seed"] = json!(format!("tsx --env-file=.env.local {}", seed_path));
+}
+
+/// Writes the schema, client, seed script and drizzle-kit config under `db/`,
+/// plus the migrations folder.
+pub fn generate_drizzle_files(
+    config: &ProjectConfig,
+    provider: &SqlProvider,
+    project_path: &Path,
+) -> Result<()> {
+    fs::write_file(&project_path.join("db/schema.ts"), schema(config, provider))?;
+    fs::write_file(&project_path.join("db/index.ts"), client(provider))?;
+    fs::write_file(&project_path.join("db/seed.ts"), SEED)?;
+    fs::write_file(
+        &project_path.join("drizzle.config.ts"),
+        self::config(provider, "./db/schema.ts"),
+    )?;
+    // `db:generate` writes SQL migrations here for `db:migrate` to apply
+    fs::write_file(&project_path.join("drizzle/.gitkeep"), "")?;
+
+    Ok(())
+}
@@ -0,0 +1,341 @@
+use crate::config::{Auth, Database, ProjectConfig};
+use crate::templates::{database, package_json};
+use crate::utils::{fs, name};
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+pub fn generate_expo_project(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    let use_clerk = matches!(config.auth, Auth::Clerk);
+    let use_convex = matches!(config.database, Database::Convex);
+
+    // Generate package.json
+    let mut deps = serde_json::Map::new();
+    deps.insert("expo".to_string(), json!("latest"));
+    deps.insert("expo-router".to_string(), json!("latest"));
+    deps.insert("expo-constants".to_string(), json!("latest"));
+    deps.insert("expo-linking".to_string(), json!("latest"));
+    deps.insert("expo-status-bar".to_string(), json!("latest"));
+    deps.insert("react".to_string(), json!("latest"));
+    deps.insert("react-native".to_string(), json!("latest"));
+    deps.insert(
+        "react-native-safe-area-context".to_string(),
+        json!("latest"),
+    );
+    deps.insert("react-native-screens".to_string(), json!("latest"));
+
+    if config.use_tailwind {
+        deps.insert("nativewind".to_string(), json!("latest"));
+        deps.insert("react-native-reanimated".to_string(), json!("latest"));
+        deps.insert("tailwindcss".to_string(), json!("^3"));
+    }
+
+    if use_clerk {
+        deps.insert("@clerk/clerk-expo".to_string(), json!("latest"));
+        deps.insert("expo-secure-store".to_string(), json!("latest"));
+    }
+
+    database::add_dependencies(config, &mut deps);
+
+    let mut package_json = json!({
+        "name": config.name,
+        "version": "0.1.0",
+        "private": true,
+        "main": "expo-router/entry",
+        "scripts": {
+            "dev": "expo start",
+            "start": "expo start",
+            "android": "expo start --android",
+            "ios": "expo start --ios",
+            "web": "expo start --web"
+        },
+        "dependencies": deps,
+        "devDependencies": {
+            "typescript": "latest",
+            "@types/react": "latest",
+            "@babel/core": "latest"
+        }
+    });
+
+    database::add_scripts(config, &mut package_json);
+
+    package_json::write_package_json(project_path, &package_json)?;
+
+    // Generate tsconfig.json
+    let mut include = vec![
+        "**/*.ts",
+        "**/*.tsx",
+        ".expo/types/**/*.ts",
+        "expo-env.d.ts",
+    ];
+    if config.use_tailwind {
+        include.push("nativewind-env.d.ts");
+    }
+    let tsconfig = json!({
+        "extends": "expo/tsconfig.base",
+        "compilerOptions": {
+            "strict": true,
+            "paths": {
+                "@/*": ["./*"]
+            }
+        },
+        "include": include
+    });
+
+    fs::write_file(
+        &project_path.join("tsconfig.json"),
+        &serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+
+    // Generate app.json
+    let slug = name::slug(&config.name);
+    // URL schemes may not contain `_`
+    let scheme = slug.replace('_', "-");
+    let app_json = json!({
+        "expo": {
+            "name": config.name,
+            "slug": slug,
+            "version": "0.1.0",
+            "scheme": scheme,
+            "orientation": "portrait",
+            "userInterfaceStyle": "automatic",
+            "newArchEnabled": true,
+            "ios": {
+                "supportsTablet": true
+            },
+            "web": {
+                "bundler": "metro",
+                "output": "static"
+            },
+            "plugins": ["expo-router"],
+            "experiments": {
+                "typedRoutes": true
+            }
+        }
+    });
+
+    fs::write_file(
+        &project_path.join("app.json"),
+        &serde_json::to_string_pretty(&app_json)?,
+    )?;
+
+    // Create app directory structure
+    fs::create_directory(&project_path.join("app"))?;
+
+    // Generate app/_layout.tsx with the selected providers
+    let mut imports = String::from("import { Stack } from 'expo-router'\n");
+    if use_clerk {
+        if use_convex {
+            imports.push_str("import { ClerkProvider, useAuth } from '@clerk/clerk-expo'\n");
+        } else {
+            imports.push_str("import { ClerkProvider } from '@clerk/clerk-expo'\n");
+        }
+        imports.push_str("import { tokenCache } from '@clerk/clerk-expo/token-cache'\n");
+    }
+    if use_convex && use_clerk {
+        imports.push_str("import { ConvexReactClient } from 'convex/react'\n");
+        imports.push_str("import { ConvexProviderWithClerk } from 'convex/react-clerk'\n");
+    } else if use_convex {
+        imports.push_str("import { ConvexProvider, ConvexReactClient } from 'convex/react'\n");
+    }
+    if config.use_tailwind {
+        imports.push_str("import '../global.css'\n");
+    }
+
+    let mut setup = String::new();
+    if use_clerk {
+        setup.push_str("const publishableKey = process.env.EXPO_PUBLIC_CLERK_PUBLISHABLE_KEY!\n\n");
+    }
+    if use_convex {
+        setup.push_str(
+            "const convex = new ConvexReactClient(process.env.EXPO_PUBLIC_CONVEX_URL!, {\n  unsavedChangesWarning: false,\n})\n\n",
+        );
+    }
+
+    // Wrap the navigator in providers, outermost first
+    let mut wrappers = Vec::new();
+    if use_clerk {
+        wrappers.push((
+            "<ClerkProvider publishableKey={publishableKey} tokenCache={tokenCache}>",
+            "</ClerkProvider>",
+        ));
+    }
+    if use_convex && use_clerk {
+        // Convex authenticates with the Clerk session's token
+        wrappers.push((
+            "<ConvexProviderWithClerk client={convex} useAuth={useAuth}>",
+            "</ConvexProviderWithClerk>",
+        ));
+    } else if use_convex {
+        wrappers.push(("<ConvexProvider client={convex}>", "</ConvexProvider>"));
+    }
+
+    let mut tree = Vec::new();
+    for (i, (open, _)) in wrappers.iter().enumerate() {
+        tree.push(format!("{}{}", "  ".repeat(i + 2), open));
+    }
+    tree.push(format!("{}<Stack />", "  ".repeat(wrappers.len() + 2)));
+    for (i, (_, close)) in wrappers.iter().enumerate().rev() {
+        tree.push(format!("{}{}", "  ".repeat(i + 2), close));
+    }
+
+    let layout_content = if wrappers.is_empty() {
+        format!(
+            "{}\n{}export default function RootLayout() {{\n  return <Stack />\n}}\n",
+            imports, setup
+        )
+    } else {
+        format!(
+            "{}\n{}export default function RootLayout() {{\n  return (\n{}\n  )\n}}\n",
+            imports,
+            setup,
+            tree.join("\n")
+        )
+    };
+    fs::write_file(&project_path.join("app/_layout.tsx"), &layout_content)?;
+
+    // Generate app/index.tsx
+    let index_content = if config.use_tailwind {
+        r#"import { Text, View } from 'react-native'
+
+export default function Index() {
+  return (
+    <View className="flex-1 items-center justify-center bg-white">
+      <Text className="text-2xl font-bold">Welcome to {{PROJECT_NAME}}</Text>
+      <Text className="mt-2 text-gray-500">Get started by editing app/index.tsx</Text>
+    </View>
+  )
+}
+"#
+    } else {
+        r#"import { StyleSheet, Text, View } from 'react-native'
+
+export default function Index() {
+  return (
+    <View style={styles.container}>
+      <Text style={styles.title}>Welcome to {{PROJECT_NAME}}</Text>
+      <Text>Get started by editing app/index.tsx</Text>
+    </View>
+  )
+}
+
+const styles = StyleSheet.create({
+  container: {
+    flex: 1,
+    alignItems: 'center',
+    justifyContent: 'center',
+  },
+  title: {
+    fontSize: 24,
+    fontWeight: 'bold',
+  },
+})
+"#
+    };
+    let index_content = index_content.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(&project_path.join("app/index.tsx"), &index_content)?;
+
+    // Add NativeWind setup if Tailwind is selected
+    if config.use_tailwind {
+        let tailwind_config = r#"/** @type {import('tailwindcss').Config} */
+module.exports = {
+  content: ['./app/**/*.{js,jsx,ts,tsx}', './components/**/*.{js,jsx,ts,tsx}'],
+  presets: [require('nativewind/preset')],
+  theme: {
+    extend: {},
+  },
+  plugins: [],
+}
+"#;
+        fs::write_file(&project_path.join("tailwind.config.js"), tailwind_config)?;
+
+        let global_css = r#"@tailwind base;
+@tailwind components;
+@tailwind utilities;
+"#;
+        fs::write_file(&project_path.join("global.css"), global_css)?;
+
+        let babel_config = r#"module.exports = function (api) {
+  api.cache(true)
+  return {
+    presets: [
+      ['babel-preset-expo', { jsxImportSource: 'nativewind' }],
+      'nativewind/babel',
+    ],
+  }
+}
+"#;
+        fs::write_file(&project_path.join("babel.config.js"), babel_config)?;
+
+        let metro_config = r#"const { getDefaultConfig } = require('expo/metro-config')
+const { withNativeWind } = require('nativewind/metro')
+
+const config = getDefaultConfig(__dirname)
+
+module.exports = withNativeWind(config, { input: './global.css' })
+"#;
+        fs::write_file(&project_path.join("metro.config.js"), metro_config)?;
+
+        fs::write_file(
+            &project_path.join("nativewind-env.d.ts"),
+            "/// <reference types=\"nativewind/types\" />\n",
+        )?;
+    }
+
+    // Add database setup if needed
+    database::generate_database_files(config, project_path)?;
+
+    // Generate .gitignore
+    let gitignore = r#"# dependencies
+/node_modules
+
+# expo
+.expo/
+dist/
+web-build/
+expo-env.d.ts
+
+# native
+/ios
+/android
+*.jks
+*.p8
+*.p12
+*.key
+*.mobileprovision
+
+# metro
+.metro-health-check*
+
+# misc
+.DS_Store
+*.pem
+
+# debug
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+
+# local env files
+.env*.local
+
+# typescript
+*.tsbuildinfo
+"#;
+    let gitignore = format!("{}{}", gitignore, database::gitignore_entries(config));
+    fs::write_file(&project_path.join(".gitignore"), &gitignore)?;
+
+    // Generate .env.local if needed
+    if use_clerk || use_convex {
+        let mut env_content = String::new();
+        if use_clerk {
+            env_content.push_str("EXPO_PUBLIC_CLERK_PUBLISHABLE_KEY=\n");
+        }
+        if use_convex {
+            env_content.push_str("EXPO_PUBLIC_CONVEX_URL=\n");
+        }
+        fs::write_file(&project_path.join(".env.local"), &env_content)?;
+    }
+
+    Ok(())
+}
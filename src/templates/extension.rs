@@ -0,0 +1,364 @@
+use crate::config::{Auth, ProjectConfig};
+use crate::templates::package_json;
+use crate::utils::fs;
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+pub fn generate_extension_project(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    let use_clerk = matches!(config.auth, Auth::Clerk);
+    // shadcn/ui components are styled with Tailwind, so it comes along
+    let use_tailwind = config.use_tailwind || config.use_shadcn;
+
+    // Generate package.json
+    let mut deps = serde_json::Map::new();
+    deps.insert("react".to_string(), json!("latest"));
+    deps.insert("react-dom".to_string(), json!("latest"));
+
+    if use_tailwind {
+        deps.insert("tailwindcss".to_string(), json!("^3"));
+        deps.insert("postcss".to_string(), json!("latest"));
+        deps.insert("autoprefixer".to_string(), json!("latest"));
+    }
+
+    if config.use_shadcn {
+        deps.insert("class-variance-authority".to_string(), json!("latest"));
+        deps.insert("clsx".to_string(), json!("latest"));
+        deps.insert("tailwind-merge".to_string(), json!("latest"));
+        deps.insert("lucide-react".to_string(), json!("latest"));
+    }
+
+    if use_clerk {
+        deps.insert("@clerk/chrome-extension".to_string(), json!("latest"));
+    }
+
+    let package_json = json!({
+        "name": config.name,
+        "version": "0.1.0",
+        "private": true,
+        "type": "module",
+        "scripts": {
+            "dev": "wxt",
+            "dev:firefox": "wxt -b firefox",
+            "build": "wxt build",
+            "build:firefox": "wxt build -b firefox",
+            "zip": "wxt zip",
+            "zip:firefox": "wxt zip -b firefox",
+            "compile": "tsc --noEmit",
+            "postinstall": "wxt prepare"
+        },
+        "dependencies": deps,
+        "devDependencies": {
+            "typescript": "latest",
+            "wxt": "latest",
+            "@wxt-dev/module-react": "latest",
+            "@types/react": "latest",
+            "@types/react-dom": "latest"
+        }
+    });
+
+    package_json::write_package_json(project_path, &package_json)?;
+
+    // Generate tsconfig.json (WXT generates the base config on `wxt prepare`)
+    let tsconfig = json!({
+        "extends": "./.wxt/tsconfig.json",
+        "compilerOptions": {
+            "allowImportingTsExtensions": true,
+            "jsx": "react-jsx"
+        }
+    });
+
+    fs::write_file(
+        &project_path.join("tsconfig.json"),
+        &serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+
+    // Generate wxt.config.ts; WXT emits an MV3 manifest for Chrome and an
+    // MV2/MV3 manifest for Firefox from the same definition
+    let permissions = if use_clerk {
+        "['storage', 'cookies']"
+    } else {
+        "['storage']"
+    };
+    let host_permissions = if use_clerk {
+        "\n    host_permissions: ['http://localhost/*'],"
+    } else {
+        ""
+    };
+    let wxt_config = format!(
+        r#"import {{ defineConfig }} from 'wxt'
+
+export default defineConfig({{
+  modules: ['@wxt-dev/module-react'],
+  manifest: {{
+    name: '{}',
+    description: 'Generated with cp-cli',
+    permissions: {},{}
+  }},
+}})
+"#,
+        config.name, permissions, host_permissions
+    );
+    fs::write_file(&project_path.join("wxt.config.ts"), &wxt_config)?;
+
+    // Generate entrypoints/background.ts
+    let background = r#"export default defineBackground(() => {
+  console.log('Background started', { id: browser.runtime.id })
+})
+"#;
+    fs::write_file(&project_path.join("entrypoints/background.ts"), background)?;
+
+    // Generate popup and options entrypoints
+    for (entry, title) in [("popup", "Popup"), ("options", "Options")] {
+        let index_html = format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1.0" />
+    <title>{} {}</title>
+  </head>
+  <body>
+    <div id="root"></div>
+    <script type="module" src="./main.tsx"></script>
+  </body>
+</html>
+"#,
+            config.name, title
+        );
+        fs::write_file(
+            &project_path.join(format!("entrypoints/{}/index.html", entry)),
+            &index_html,
+        )?;
+
+        let mut main_content = String::from(
+            "import { StrictMode } from 'react'\nimport { createRoot } from 'react-dom/client'\n",
+        );
+        if use_clerk && entry == "popup" {
+            main_content.push_str("import { ClerkProvider } from '@clerk/chrome-extension'\n");
+        }
+        main_content.push_str("import App from './App'\n");
+        if use_tailwind {
+            main_content.push_str("import '@/assets/tailwind.css'\n");
+        }
+        if use_clerk && entry == "popup" {
+            main_content.push_str(
+                r#"
+const PUBLISHABLE_KEY = import.meta.env.VITE_CLERK_PUBLISHABLE_KEY
+
+if (!PUBLISHABLE_KEY) {
+  throw new Error('Missing VITE_CLERK_PUBLISHABLE_KEY')
+}
+
+createRoot(document.getElementById('root')!).render(
+  <StrictMode>
+    <ClerkProvider
+      publishableKey={PUBLISHABLE_KEY}
+      afterSignOutUrl={browser.runtime.getURL('/popup.html')}
+      signInFallbackRedirectUrl={browser.runtime.getURL('/popup.html')}
+      signUpFallbackRedirectUrl={browser.runtime.getURL('/popup.html')}
+    >
+      <App />
+    </ClerkProvider>
+  </StrictMode>,
+)
+"#,
+            );
+        } else {
+            main_content.push_str(
+                r#"
+createRoot(document.getElementById('root')!).render(
+  <StrictMode>
+    <App />
+  </StrictMode>,
+)
+"#,
+            );
+        }
+        fs::write_file(
+            &project_path.join(format!("entrypoints/{}/main.tsx", entry)),
+            &main_content,
+        )?;
+    }
+
+    let popup_app = if use_clerk {
+        r#"import {
+  SignedIn,
+  SignedOut,
+  SignInButton,
+  UserButton,
+} from '@clerk/chrome-extension'
+
+export default function App() {
+  return (
+    <main className="popup">
+      <h1>{{PROJECT_NAME}}</h1>
+      <SignedOut>
+        <SignInButton mode="modal" />
+      </SignedOut>
+      <SignedIn>
+        <UserButton />
+      </SignedIn>
+    </main>
+  )
+}
+"#
+    } else {
+        r#"export default function App() {
+  return (
+    <main className="popup">
+      <h1>{{PROJECT_NAME}}</h1>
+      <p>Get started by editing entrypoints/popup/App.tsx</p>
+    </main>
+  )
+}
+"#
+    };
+    let popup_app = popup_app.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(&project_path.join("entrypoints/popup/App.tsx"), &popup_app)?;
+
+    let options_app = r#"export default function App() {
+  return (
+    <main>
+      <h1>{{PROJECT_NAME}} options</h1>
+      <p>Get started by editing entrypoints/options/App.tsx</p>
+    </main>
+  )
+}
+"#;
+    let options_app = options_app.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(
+        &project_path.join("entrypoints/options/App.tsx"),
+        &options_app,
+    )?;
+
+    // Add Tailwind config if needed
+    if use_tailwind {
+        let tailwind_css = r#"@tailwind base;
+@tailwind components;
+@tailwind utilities;
+
+.popup {
+  @apply w-80 p-4;
+}
+"#;
+        fs::write_file(&project_path.join("assets/tailwind.css"), tailwind_css)?;
+
+        let tailwind_config = r#"/** @type {import('tailwindcss').Config} */
+export default {
+  content: ['./entrypoints/**/*.{html,ts,tsx}', './components/**/*.{ts,tsx}'],
+  theme: {
+    extend: {},
+  },
+  plugins: [],
+}
+"#;
+        fs::write_file(&project_path.join("tailwind.config.js"), tailwind_config)?;
+
+        let postcss_config = r#"export default {
+  plugins: {
+    tailwindcss: {},
+    autoprefixer: {},
+  },
+}
+"#;
+        fs::write_file(&project_path.join("postcss.config.js"), postcss_config)?;
+    }
+
+    // Add shadcn/ui config if needed
+    if config.use_shadcn {
+        let components_json = json!({
+            "$schema": "https://ui.shadcn.com/schema.json",
+            "style": "new-york",
+            "rsc": false,
+            "tsx": true,
+            "tailwind": {
+                "config": "tailwind.config.js",
+                "css": "assets/tailwind.css",
+                "baseColor": "neutral",
+                "cssVariables": true
+            },
+            "aliases": {
+                "components": "@/components",
+                "utils": "@/lib/utils",
+                "ui": "@/components/ui",
+                "lib": "@/lib",
+                "hooks": "@/hooks"
+            }
+        });
+        fs::write_file(
+            &project_path.join("components.json"),
+            &serde_json::to_string_pretty(&components_json)?,
+        )?;
+
+        let utils = r#"import { clsx, type ClassValue } from 'clsx'
+import { twMerge } from 'tailwind-merge'
+
+export function cn(...inputs: ClassValue[]) {
+  return twMerge(clsx(inputs))
+}
+"#;
+        fs::write_file(&project_path.join("lib/utils.ts"), utils)?;
+    }
+
+    // Generate README.md
+    let pm = &config.package_manager;
+    let mut readme = format!(
+        "# {}\n\nA browser extension built with WXT, generated with cp-cli.\n\n## Getting started\n\n```bash\n{}\n{}\n```\n\n## Scripts\n\n- `{}` - develop in Chrome with hot reload\n- `{}` - develop in Firefox\n- `{}` / `{}` - build for Chrome / Firefox\n- `{}` / `{}` - package for the Chrome Web Store / Firefox Add-ons\n",
+        config.name,
+        pm.install_cmd(),
+        pm.dev_cmd(),
+        pm.run_cmd("dev"),
+        pm.run_cmd("dev:firefox"),
+        pm.run_cmd("build"),
+        pm.run_cmd("build:firefox"),
+        pm.run_cmd("zip"),
+        pm.run_cmd("zip:firefox"),
+    );
+    if config.use_shadcn {
+        readme.push_str(&format!(
+            "\n## UI components\n\nAdd shadcn/ui components with `{}`.\n",
+            pm.exec_cmd("shadcn@latest add button")
+        ));
+    }
+    if use_clerk {
+        readme.push_str("\n## Authentication\n\nClerk needs a stable extension ID. Add a `key` to the manifest in `wxt.config.ts` and allow the extension origin in the Clerk dashboard.\n");
+    }
+    fs::write_file(&project_path.join("README.md"), &readme)?;
+
+    // Generate .gitignore
+    let gitignore = r#"# dependencies
+/node_modules
+
+# wxt
+.output
+.wxt
+web-ext.config.ts
+
+# misc
+.DS_Store
+*.pem
+
+# debug
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+
+# local env files
+.env*.local
+
+# typescript
+*.tsbuildinfo
+"#;
+    fs::write_file(&project_path.join(".gitignore"), gitignore)?;
+
+    // Generate .env.local if needed
+    if use_clerk {
+        fs::write_file(
+            &project_path.join(".env.local"),
+            "VITE_CLERK_PUBLISHABLE_KEY=\n",
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use crate::config::{Framework, ProjectConfig};
+use crate::utils::fs;
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+/// Emulator-only project ID. The `demo-` prefix keeps the Firebase CLI and
+/// SDKs from ever reaching a real project.
+pub fn project_id(config: &ProjectConfig) -> String {
+    let name: String = config
+        .name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    format!("demo-{}", name.trim_matches('-'))
+}
+
+/// `KEY=value` lines for the client config, emulators and admin credentials.
+pub fn env_vars(config: &ProjectConfig) -> Vec<String> {
+    let prefix = config.framework.public_env_prefix();
+    let project_id = project_id(config);
+    vec![
+        format!("{}FIREBASE_API_KEY=demo-api-key", prefix),
+        format!(
+            "{}FIREBASE_AUTH_DOMAIN={}.firebaseapp.com",
+            prefix, project_id
+        ),
+        format!("{}FIREBASE_PROJECT_ID={}", prefix, project_id),
+        format!(
+            "{}FIREBASE_STORAGE_BUCKET={}.appspot.com",
+            prefix, project_id
+        ),
+        format!("{}FIREBASE_MESSAGING_SENDER_ID=", prefix),
+        format!("{}FIREBASE_APP_ID=", prefix),
+        format!("{}FIREBASE_USE_EMULATORS=true", prefix),
+        "# Read by the Admin SDK; remove both to use the real project".to_string(),
+        "FIREBASE_AUTH_EMULATOR_HOST=127.0.0.1:9099".to_string(),
+        "FIRESTORE_EMULATOR_HOST=127.0.0.1:8080".to_string(),
+        "# Service account for the Admin SDK outside the emulators".to_string(),
+        "FIREBASE_CLIENT_EMAIL=".to_string(),
+        "FIREBASE_PRIVATE_KEY=".to_string(),
+    ]
+}
+
+/// Writes the Firebase CLI project (`firebase.json`, rules, emulators) and the
+/// client and Admin SDK modules.
+pub fn generate_firebase_files(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    let firebase_json = json!({
+        "firestore": {
+            "rules": "firestore.rules",
+            "indexes": "firestore.indexes.json"
+        },
+        "emulators": {
+            "auth": { "port": 9099 },
+            "firestore": { "port": 8080 },
+            "ui": { "enabled": true, "port": 4000 },
+            "singleProjectMode": true
+        }
+    });
+    fs::write_file(
+        &project_path.join("firebase.json"),
+        &serde_json::to_string_pretty(&firebase_json)?,
+    )?;
+
+    let firebaserc = json!({
+        "projects": {
+            "default": project_id(config)
+        }
+    });
+    fs::write_file(
+        &project_path.join(".firebaserc"),
+        &serde_json::to_string_pretty(&firebaserc)?,
+    )?;
+
+    let rules = r#"rules_version = '2';
+
+service cloud.firestore {
+  match /databases/{database}/documents {
+    // Each signed-in user can read and write their own profile
+    match /users/{userId} {
+      allow read, write: if request.auth != null && request.auth.uid == userId;
+    }
+  }
+}
+"#;
+    fs::write_file(&project_path.join("firestore.rules"), rules)?;
+
+    let indexes = json!({
+        "indexes": [],
+        "fieldOverrides": []
+    });
+    fs::write_file(
+        &project_path.join("firestore.indexes.json"),
+        &serde_json::to_string_pretty(&indexes)?,
+    )?;
+
+    // Generate lib/firebase/client.ts
+    let env = match config.framework {
+        Framework::NextJs => "process.env.NEXT_PUBLIC_",
+        Framework::TanStackStart => "import.meta.env.VITE_",
+        _ => return Ok(()),
+    };
+    let client = r#"import { getApp, getApps, initializeApp } from 'firebase/app'
+import { connectAuthEmulator, getAuth } from 'firebase/auth'
+import { connectFirestoreEmulator, getFirestore } from 'firebase/firestore'
+
+const firebaseConfig = {
+  apiKey: {{ENV}}FIREBASE_API_KEY,
+  authDomain: {{ENV}}FIREBASE_AUTH_DOMAIN,
+  projectId: {{ENV}}FIREBASE_PROJECT_ID,
+  storageBucket: {{ENV}}FIREBASE_STORAGE_BUCKET,
+  messagingSenderId: {{ENV}}FIREBASE_MESSAGING_SENDER_ID,
+  appId: {{ENV}}FIREBASE_APP_ID,
+}
+
+export const app = getApps().length ? getApp() : initializeApp(firebaseConfig)
+export const auth = getAuth(app)
+export const db = getFirestore(app)
+
+// Emulators can only be connected once per app, which hot reloads would repeat
+const globalForFirebase = globalThis as unknown as { firebaseEmulators?: boolean }
+
+if ({{ENV}}FIREBASE_USE_EMULATORS === 'true' && !globalForFirebase.firebaseEmulators) {
+  connectAuthEmulator(auth, 'http://127.0.0.1:9099', { disableWarnings: true })
+  connectFirestoreEmulator(db, '127.0.0.1', 8080)
+  globalForFirebase.firebaseEmulators = true
+}
+"#;
+    let client = client.replace("{{ENV}}", env);
+    fs::write_file(&project_path.join("lib/firebase/client.ts"), &client)?;
+
+    // Generate lib/firebase/admin.ts (server only)
+    let admin = r#"import { cert, getApps, initializeApp } from 'firebase-admin/app'
+import { getAuth } from 'firebase-admin/auth'
+import { getFirestore } from 'firebase-admin/firestore'
+
+const projectId = process.env.{{PREFIX}}FIREBASE_PROJECT_ID
+
+// With the *_EMULATOR_HOST variables set the Admin SDK talks to the emulators
+// and needs no service account
+const app =
+  getApps()[0] ??
+  initializeApp(
+    process.env.FIREBASE_PRIVATE_KEY
+      ? {
+          credential: cert({
+            projectId,
+            clientEmail: process.env.FIREBASE_CLIENT_EMAIL,
+            privateKey: process.env.FIREBASE_PRIVATE_KEY.replace(/\\n/g, '\n'),
+          }),
+        }
+      : { projectId },
+  )
+
+export const adminAuth = getAuth(app)
+export const adminDb = getFirestore(app)
+"#;
+    let admin = admin.replace("{{PREFIX}}", config.framework.public_env_prefix());
+    fs::write_file(&project_path.join("lib/firebase/admin.ts"), &admin)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::fixtures;
+
+    #[test]
+    fn project_id_is_a_lowercase_demo_id() {
+        assert_eq!(project_id(&fixtures::project("my-app")), "demo-my-app");
+        assert_eq!(
+            project_id(&fixtures::project("My_App v2")),
+            "demo-my-app-v2"
+        );
+    }
+
+    #[test]
+    fn project_id_trims_replaced_edges() {
+        assert_eq!(
+            project_id(&fixtures::project("@acme/shop.")),
+            "demo-acme-shop"
+        );
+    }
+}
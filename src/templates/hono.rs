@@ -0,0 +1,229 @@
+use crate::config::{Database, Orm, ProjectConfig, Runtime, SqlProvider};
+use crate::templates::{database, package_json};
+use crate::utils::fs;
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+pub fn generate_hono_project(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    let use_drizzle = matches!(config.database, Database::Sql(Orm::Drizzle, _));
+
+    // Generate package.json
+    let mut deps = serde_json::Map::new();
+    deps.insert("hono".to_string(), json!("latest"));
+    deps.insert("zod".to_string(), json!("latest"));
+    deps.insert("@hono/zod-validator".to_string(), json!("latest"));
+
+    let mut dev_deps = serde_json::Map::new();
+    dev_deps.insert("typescript".to_string(), json!("latest"));
+
+    let scripts = match config.runtime {
+        Runtime::Node => {
+            deps.insert("@hono/node-server".to_string(), json!("latest"));
+            dev_deps.insert("@types/node".to_string(), json!("latest"));
+            dev_deps.insert("tsx".to_string(), json!("latest"));
+            dev_deps.insert("tsup".to_string(), json!("latest"));
+            json!({
+                "dev": "tsx watch --env-file=.env.local src/index.ts",
+                "build": "tsup src/index.ts --format esm --clean",
+                "start": "node dist/index.js"
+            })
+        }
+        Runtime::Bun => {
+            dev_deps.insert("@types/bun".to_string(), json!("latest"));
+            json!({
+                "dev": "bun --hot src/index.ts",
+                "build": "bun build src/index.ts --target bun --outdir dist",
+                "start": "bun run dist/index.js"
+            })
+        }
+    };
+
+    database::add_dependencies(config, &mut deps);
+
+    let mut package_json = json!({
+        "name": config.name,
+        "version": "0.1.0",
+        "private": true,
+        "type": "module",
+        "scripts": scripts,
+        "dependencies": deps,
+        "devDependencies": dev_deps
+    });
+
+    database::add_scripts(config, &mut package_json);
+
+    package_json::write_package_json(project_path, &package_json)?;
+
+    // Generate tsconfig.json
+    let types = match config.runtime {
+        Runtime::Node => "node",
+        Runtime::Bun => "bun",
+    };
+    let tsconfig = json!({
+        "compilerOptions": {
+            "target": "ES2022",
+            "lib": ["esnext"],
+            "skipLibCheck": true,
+            "strict": true,
+            "noEmit": true,
+            "esModuleInterop": true,
+            "module": "esnext",
+            "moduleResolution": "bundler",
+            "resolveJsonModule": true,
+            "isolatedModules": true,
+            "types": [types]
+        },
+        "include": ["src/**/*.ts", "db/**/*.ts"],
+        "exclude": ["node_modules", "dist"]
+    });
+
+    fs::write_file(
+        &project_path.join("tsconfig.json"),
+        &serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+
+    // Create src directory structure
+    fs::create_directory(&project_path.join("src"))?;
+
+    // Generate src/index.ts
+    let mut imports = String::new();
+    if matches!(config.runtime, Runtime::Node) {
+        imports.push_str("import { serve } from '@hono/node-server'\n");
+    }
+    imports.push_str("import { Hono } from 'hono'\nimport { logger } from 'hono/logger'\nimport { zValidator } from '@hono/zod-validator'\nimport { z } from 'zod'\n");
+    if use_drizzle {
+        imports.push_str("import { eq } from 'drizzle-orm'\nimport { db } from '../db'\nimport { users } from '../db/schema'\n");
+    }
+
+    // MySQL has no RETURNING clause; Drizzle hands back the new id instead
+    let insert_user = if matches!(config.database, Database::Sql(_, SqlProvider::PlanetScale)) {
+        r#"    const [{ id }] = await db.insert(users).values(body).$returningId()
+    const [user] = await db.select().from(users).where(eq(users.id, id))"#
+    } else {
+        "    const [user] = await db.insert(users).values(body).returning()"
+    };
+
+    let routes = if use_drizzle {
+        r#"const createUserSchema = z.object({
+  name: z.string().min(1),
+  email: z.string().email(),
+})
+
+const userParamsSchema = z.object({
+  id: z.coerce.number().int().positive(),
+})
+
+const routes = app
+  .get('/users', async (c) => {
+    const rows = await db.select().from(users)
+    return c.json(rows)
+  })
+  .get('/users/:id', zValidator('param', userParamsSchema), async (c) => {
+    const { id } = c.req.valid('param')
+    const [user] = await db.select().from(users).where(eq(users.id, id))
+    if (!user) {
+      return c.json({ error: 'User not found' }, 404)
+    }
+    return c.json(user)
+  })
+  .post('/users', zValidator('json', createUserSchema), async (c) => {
+    const body = c.req.valid('json')
+{{INSERT_USER}}
+    return c.json(user, 201)
+  })
+"#
+    } else {
+        r#"const greetingSchema = z.object({
+  name: z.string().min(1),
+})
+
+const routes = app
+  .get('/hello/:name', (c) => {
+    return c.json({ message: `Hello, ${c.req.param('name')}!` })
+  })
+  .post('/greetings', zValidator('json', greetingSchema), (c) => {
+    const { name } = c.req.valid('json')
+    return c.json({ message: `Hello, ${name}!` }, 201)
+  })
+"#
+    };
+
+    let server = match config.runtime {
+        Runtime::Node => {
+            r#"const port = Number(process.env.PORT ?? 3000)
+
+serve({ fetch: app.fetch, port }, (info) => {
+  console.log(`Server listening on http://localhost:${info.port}`)
+})
+"#
+        }
+        Runtime::Bun => {
+            r#"export default {
+  port: Number(process.env.PORT ?? 3000),
+  fetch: app.fetch,
+}
+"#
+        }
+    };
+
+    let index_content = format!(
+        "{}\nconst app = new Hono()\n\napp.use(logger())\n\napp.get('/health', (c) => c.json({{ status: 'ok' }}))\n\n{}\nexport type AppType = typeof routes\n\n{}",
+        imports,
+        routes.replace("{{INSERT_USER}}", insert_user),
+        server
+    );
+    fs::write_file(&project_path.join("src/index.ts"), &index_content)?;
+
+    // Add database setup if needed
+    database::generate_database_files(config, project_path)?;
+
+    // Generate README.md
+    let pm = &config.package_manager;
+    let readme = format!(
+        "# {}\n\nA Hono API server ({}) generated with cp-cli.\n\n## Getting started\n\n```bash\n{}\n{}\n```\n\nThen check `http://localhost:3000/health`.\n\n## Scripts\n\n- `{}` - start the server with hot reload\n- `{}` - bundle for production\n- `{}` - run the production build\n",
+        config.name,
+        config.runtime,
+        pm.install_cmd(),
+        pm.dev_cmd(),
+        pm.run_cmd("dev"),
+        pm.run_cmd("build"),
+        pm.run_cmd("start"),
+    );
+    fs::write_file(&project_path.join("README.md"), &readme)?;
+
+    // Generate .gitignore
+    let gitignore = r#"# dependencies
+/node_modules
+
+# build
+/dist
+
+# misc
+.DS_Store
+*.pem
+
+# debug
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+
+# local env files
+.env*.local
+
+# typescript
+*.tsbuildinfo
+"#;
+    let gitignore = format!("{}{}", gitignore, database::gitignore_entries(config));
+    fs::write_file(&project_path.join(".gitignore"), &gitignore)?;
+
+    // Generate .env.local
+    let mut env_content = String::from("PORT=3000\n");
+    for line in database::env_vars(config) {
+        env_content.push_str(&line);
+        env_content.push('\n');
+    }
+    fs::write_file(&project_path.join(".env.local"), &env_content)?;
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use crate::config::SqlProvider;
+use crate::templates::database;
+use crate::utils::fs;
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+const TYPES: &str = r#"import type { Generated } from 'kysely';
+
+// Keep in sync with the database; `kysely-codegen` can generate this file
+export interface UsersTable {
+  id: Generated<number>;
+  name: string;
+  email: string;
+  created_at: Generated<Date>;
+}
+
+export interface Database {
+  users: UsersTable;
+}
+"#;
+
+fn client(provider: &SqlProvider) -> &'static str {
+    match provider {
+        SqlProvider::Neon => {
+            r#"import { Pool } from '@neondatabase/serverless';
+import { Kysely, PostgresDialect } from 'kysely';
+import type { Database } from './types';
+
+export const db = new Kysely<Database>({
+  dialect: new PostgresDialect({
+    pool: new Pool({ connectionString: process.env.DATABASE_URL }),
+  }),
+});
+"#
+        }
+        SqlProvider::Postgres => {
+            r#"import { Kysely, PostgresDialect } from 'kysely';
+import { Pool } from 'pg';
+import type { Database } from './types';
+
+export const db = new Kysely<Database>({
+  dialect: new PostgresDialect({
+    pool: new Pool({ connectionString: process.env.DATABASE_URL }),
+  }),
+});
+"#
+        }
+        SqlProvider::Turso => {
+            r#"import { LibsqlDialect } from '@libsql/kysely-libsql';
+import { Kysely } from 'kysely';
+import type { Database } from './types';
+
+export const db = new Kysely<Database>({
+  dialect: new LibsqlDialect({
+    url: process.env.DATABASE_URL!,
+    authToken: process.env.DATABASE_AUTH_TOKEN,
+  }),
+});
+"#
+        }
+        SqlProvider::Sqlite => {
+            r#"import SQLite from 'better-sqlite3';
+import { Kysely, SqliteDialect } from 'kysely';
+import type { Database } from './types';
+
+export const db = new Kysely<Database>({
+  dialect: new SqliteDialect({
+    database: new SQLite(process.env.DATABASE_URL ?? 'local.db'),
+  }),
+});
+"#
+        }
+        SqlProvider::PlanetScale => {
+            r#"import { Kysely } from 'kysely';
+import { PlanetScaleDialect } from 'kysely-planetscale';
+import type { Database } from './types';
+
+export const db = new Kysely<Database>({
+  dialect: new PlanetScaleDialect({ url: process.env.DATABASE_URL }),
+});
+"#
+        }
+    }
+}
+
+pub fn add_dependencies(
+    provider: &SqlProvider,
+    deps: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    deps.insert("kysely".to_string(), json!("latest"));
+    for package in database::driver_packages(provider) {
+        deps.insert(package.to_string(), json!("latest"));
+    }
+    match provider {
+        SqlProvider::Turso => {
+            deps.insert("@libsql/kysely-libsql".to_string(), json!("latest"));
+        }
+        SqlProvider::PlanetScale => {
+            deps.insert("kysely-planetscale".to_string(), json!("latest"));
+        }
+        SqlProvider::Neon | SqlProvider::Postgres | SqlProvider::Sqlite => {}
+    }
+}
+
+/// Writes the table types and the Kysely instance under `db/`.
+pub fn generate_kysely_files(provider: &SqlProvider, project_path: &Path) -> Result<()> {
+    fs::write_file(&project_path.join("db/types.ts"), TYPES)?;
+    fs::write_file(&project_path.join("db/index.ts"), client(provider))?;
+
+    Ok(())
+}
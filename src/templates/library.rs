@@ -0,0 +1,193 @@
+use crate::config::ProjectConfig;
+use crate::templates::package_json;
+use crate::utils::fs;
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+pub fn generate_library_project(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    // Generate package.json with an exports map covering ESM, CJS and types
+    let package_json = json!({
+        "name": config.name,
+        "version": "0.1.0",
+        "description": "",
+        "license": "MIT",
+        "type": "module",
+        "main": "./dist/index.cjs",
+        "module": "./dist/index.js",
+        "types": "./dist/index.d.ts",
+        "exports": {
+            ".": {
+                "import": {
+                    "types": "./dist/index.d.ts",
+                    "default": "./dist/index.js"
+                },
+                "require": {
+                    "types": "./dist/index.d.cts",
+                    "default": "./dist/index.cjs"
+                }
+            },
+            "./package.json": "./package.json"
+        },
+        "files": ["dist"],
+        "sideEffects": false,
+        "scripts": {
+            "build": "tsup",
+            "dev": "tsup --watch",
+            "test": "vitest run",
+            "test:watch": "vitest",
+            "typecheck": "tsc --noEmit",
+            "changeset": "changeset",
+            "version-packages": "changeset version",
+            "release": "tsup && changeset publish"
+        },
+        "publishConfig": {
+            "access": "public"
+        },
+        "devDependencies": {
+            "typescript": "latest",
+            "tsup": "latest",
+            "vitest": "latest",
+            "@changesets/cli": "latest",
+            "@types/node": "latest"
+        }
+    });
+
+    package_json::write_package_json(project_path, &package_json)?;
+
+    // Generate tsconfig.json
+    let tsconfig = json!({
+        "compilerOptions": {
+            "target": "ES2020",
+            "lib": ["esnext"],
+            "skipLibCheck": true,
+            "strict": true,
+            "noEmit": true,
+            "esModuleInterop": true,
+            "module": "esnext",
+            "moduleResolution": "bundler",
+            "resolveJsonModule": true,
+            "isolatedModules": true,
+            "declaration": true
+        },
+        "include": ["src"],
+        "exclude": ["node_modules", "dist"]
+    });
+
+    fs::write_file(
+        &project_path.join("tsconfig.json"),
+        &serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+
+    // Generate tsup.config.ts
+    let tsup_config = r#"import { defineConfig } from 'tsup'
+
+export default defineConfig({
+  entry: ['src/index.ts'],
+  format: ['esm', 'cjs'],
+  dts: true,
+  sourcemap: true,
+  clean: true,
+})
+"#;
+    fs::write_file(&project_path.join("tsup.config.ts"), tsup_config)?;
+
+    // Generate vitest.config.ts
+    let vitest_config = r#"import { defineConfig } from 'vitest/config'
+
+export default defineConfig({
+  test: {
+    include: ['src/**/*.test.ts'],
+  },
+})
+"#;
+    fs::write_file(&project_path.join("vitest.config.ts"), vitest_config)?;
+
+    // Generate src/index.ts and a sample test
+    let index_content = r#"export function greet(name: string): string {
+  return `Hello, ${name}!`
+}
+"#;
+    fs::write_file(&project_path.join("src/index.ts"), index_content)?;
+
+    let test_content = r#"import { describe, expect, it } from 'vitest'
+import { greet } from './index'
+
+describe('greet', () => {
+  it('greets by name', () => {
+    expect(greet('world')).toBe('Hello, world!')
+  })
+})
+"#;
+    fs::write_file(&project_path.join("src/index.test.ts"), test_content)?;
+
+    // Generate changesets config
+    let changeset_config = json!({
+        "$schema": "https://unpkg.com/@changesets/config/schema.json",
+        "changelog": "@changesets/cli/changelog",
+        "commit": false,
+        "fixed": [],
+        "linked": [],
+        "access": "public",
+        "baseBranch": "main",
+        "updateInternalDependencies": "patch",
+        "ignore": []
+    });
+    fs::write_file(
+        &project_path.join(".changeset/config.json"),
+        &serde_json::to_string_pretty(&changeset_config)?,
+    )?;
+
+    let changeset_readme = r#"# Changesets
+
+Run `changeset` to describe a change. Each changeset records which packages
+to release and at which semver level; `changeset version` applies them.
+"#;
+    fs::write_file(&project_path.join(".changeset/README.md"), changeset_readme)?;
+
+    // Generate README.md
+    let pm = &config.package_manager;
+    let readme = format!(
+        "# {}\n\nA TypeScript library generated with cp-cli.\n\n## Usage\n\n```ts\nimport {{ greet }} from '{}'\n\ngreet('world')\n```\n\n## Development\n\n```bash\n{}\n{}\n```\n\n## Scripts\n\n- `{}` - build ESM, CJS and type declarations into `dist/`\n- `{}` - rebuild on change\n- `{}` - run the test suite\n- `{}` - record a change for the next release\n- `{}` - bump versions and write changelogs\n- `{}` - build and publish to npm\n",
+        config.name,
+        config.name,
+        pm.install_cmd(),
+        pm.run_cmd("test"),
+        pm.run_cmd("build"),
+        pm.run_cmd("dev"),
+        pm.run_cmd("test"),
+        pm.run_cmd("changeset"),
+        pm.run_cmd("version-packages"),
+        pm.run_cmd("release"),
+    );
+    fs::write_file(&project_path.join("README.md"), &readme)?;
+
+    // Generate .gitignore
+    let gitignore = r#"# dependencies
+/node_modules
+
+# build
+/dist
+
+# testing
+/coverage
+
+# misc
+.DS_Store
+*.pem
+
+# debug
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+
+# local env files
+.env*.local
+
+# typescript
+*.tsbuildinfo
+"#;
+    fs::write_file(&project_path.join(".gitignore"), gitignore)?;
+
+    Ok(())
+}
@@ -1,7 +1,31 @@
+pub mod authjs;
+pub mod better_auth;
+pub mod cli;
+pub mod convex;
+pub mod database;
+pub mod docker_compose;
+pub mod docs;
+pub mod drizzle;
+pub mod expo;
+pub mod extension;
+pub mod firebase;
+pub mod hono;
+pub mod kysely;
+pub mod library;
+pub mod mongodb;
+pub mod monorepo;
 pub mod nextjs;
+pub mod node_server;
+pub mod package_json;
+pub mod prisma;
+pub mod redis;
+pub mod solid_start;
+pub mod supabase;
 pub mod tanstack;
+pub mod tauri;
+pub mod vite_react;
 
-use crate::config::ProjectConfig;
+use crate::config::{Database, ProjectConfig, Redis};
 use anyhow::Result;
 use std::path::Path;
 
@@ -11,5 +35,179 @@ pub fn generate_project(config: &ProjectConfig, project_path: &Path) -> Result<(
         crate::config::Framework::TanStackStart => {
             tanstack::generate_tanstack_project(config, project_path)
         }
+        crate::config::Framework::ViteReact => {
+            vite_react::generate_vite_react_project(config, project_path)
+        }
+        crate::config::Framework::Hono => hono::generate_hono_project(config, project_path),
+        crate::config::Framework::Express | crate::config::Framework::Fastify => {
+            node_server::generate_node_server_project(config, project_path)
+        }
+        crate::config::Framework::SolidStart => {
+            solid_start::generate_solid_start_project(config, project_path)
+        }
+        crate::config::Framework::Expo => expo::generate_expo_project(config, project_path),
+        crate::config::Framework::BrowserExtension => {
+            extension::generate_extension_project(config, project_path)
+        }
+        crate::config::Framework::Tauri => tauri::generate_tauri_project(config, project_path),
+        crate::config::Framework::Fumadocs | crate::config::Framework::Starlight => {
+            docs::generate_docs_project(config, project_path)
+        }
+        crate::config::Framework::Cli => cli::generate_cli_project(config, project_path),
+        crate::config::Framework::Library => {
+            library::generate_library_project(config, project_path)
+        }
+    }
+}
+
+/// Rejects feature toggles the selected framework has no template support for.
+pub fn check_supported(config: &ProjectConfig) -> Result<()> {
+    let framework = &config.framework;
+    if !framework.auth_providers().contains(&config.auth) {
+        anyhow::bail!("{} is not supported with {}", config.auth, framework);
+    }
+    if config.use_tailwind && !framework.supports_tailwind() {
+        anyhow::bail!("Tailwind CSS is not supported with {}", framework);
+    }
+    if config.use_shadcn && !framework.supports_shadcn() {
+        anyhow::bail!("shadcn/ui is not supported with {}", framework);
+    }
+    if !matches!(config.redis, Redis::None) && !framework.supports_redis() {
+        anyhow::bail!("Redis is not supported with {}", framework);
+    }
+    let database_supported = match &config.database {
+        Database::Sql(..) => framework.supports_sql(),
+        database => framework.databases().contains(database),
+    };
+    if !database_supported {
+        anyhow::bail!("{} is not supported with {}", config.database, framework);
+    }
+    if !config.runtime.supports(&config.database) {
+        anyhow::bail!(
+            "{} is not supported with {}",
+            config.database,
+            config.runtime
+        );
+    }
+    if !config.auth.supports(&config.database) {
+        anyhow::bail!("{} is not supported with {}", config.auth, config.database);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Auth, Framework, Orm, Runtime, SqlProvider, fixtures};
+
+    fn error(config: &ProjectConfig) -> String {
+        check_supported(config).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn accepts_the_defaults() {
+        assert!(check_supported(&fixtures::project("app")).is_ok());
+    }
+
+    #[test]
+    fn rejects_features_the_framework_lacks() {
+        let config = ProjectConfig {
+            framework: Framework::Hono,
+            auth: Auth::Clerk,
+            ..fixtures::project("api")
+        };
+        assert_eq!(error(&config), "Clerk is not supported with Hono");
+
+        let config = ProjectConfig {
+            framework: Framework::TanStackStart,
+            auth: Auth::NextAuth,
+            ..fixtures::project("web")
+        };
+        assert_eq!(
+            error(&config),
+            "Auth.js (NextAuth v5) is not supported with TanStack Start"
+        );
+
+        let config = ProjectConfig {
+            framework: Framework::Express,
+            use_tailwind: true,
+            ..fixtures::project("api")
+        };
+        assert_eq!(error(&config), "Tailwind CSS is not supported with Express");
+
+        let config = ProjectConfig {
+            framework: Framework::SolidStart,
+            use_shadcn: true,
+            ..fixtures::project("web")
+        };
+        assert_eq!(error(&config), "shadcn/ui is not supported with SolidStart");
+
+        let config = ProjectConfig {
+            framework: Framework::ViteReact,
+            redis: Redis::Upstash,
+            ..fixtures::project("web")
+        };
+        assert_eq!(error(&config), "Redis is not supported with Vite + React");
+    }
+
+    #[test]
+    fn accepts_any_orm_and_provider_for_sql() {
+        for database in [
+            Database::Sql(Orm::Drizzle, SqlProvider::Neon),
+            Database::Sql(Orm::Prisma, SqlProvider::Postgres),
+            Database::Sql(Orm::Kysely, SqlProvider::PlanetScale),
+            Database::Sql(Orm::None, SqlProvider::Sqlite),
+        ] {
+            let config = ProjectConfig {
+                framework: Framework::Hono,
+                database,
+                ..fixtures::project("api")
+            };
+            assert!(check_supported(&config).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejects_databases_the_framework_cannot_use() {
+        let config = ProjectConfig {
+            framework: Framework::ViteReact,
+            database: Database::Sql(Orm::Prisma, SqlProvider::Postgres),
+            ..fixtures::project("web")
+        };
+        assert_eq!(
+            error(&config),
+            "Prisma + PostgreSQL is not supported with Vite + React"
+        );
+
+        let config = ProjectConfig {
+            framework: Framework::Hono,
+            database: Database::Convex,
+            ..fixtures::project("api")
+        };
+        assert_eq!(error(&config), "Convex is not supported with Hono");
+    }
+
+    #[test]
+    fn rejects_sqlite_drivers_bun_cannot_load() {
+        let mut config = ProjectConfig {
+            framework: Framework::Hono,
+            database: Database::Sql(Orm::Drizzle, SqlProvider::Sqlite),
+            runtime: Runtime::Bun,
+            ..fixtures::project("api")
+        };
+        assert!(check_supported(&config).is_err());
+        config.database = Database::Sql(Orm::Drizzle, SqlProvider::Turso);
+        assert!(check_supported(&config).is_ok());
+    }
+
+    #[test]
+    fn rejects_better_auth_without_an_adapter() {
+        let config = ProjectConfig {
+            database: Database::Convex,
+            auth: Auth::BetterAuth,
+            ..fixtures::project("web")
+        };
+        assert_eq!(error(&config), "Better Auth is not supported with Convex");
     }
 }
@@ -0,0 +1,81 @@
+use crate::config::{Framework, ProjectConfig};
+use crate::templates::database;
+use crate::utils::fs;
+use anyhow::Result;
+use std::path::Path;
+
+/// `MONGODB_URI` for the docker-compose service.
+pub fn database_url(config: &ProjectConfig) -> String {
+    format!(
+        "mongodb://localhost:27017/{}",
+        database::database_name(config)
+    )
+}
+
+/// Writes the connection helper and a sample Mongoose model.
+pub fn generate_mongodb_files(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    // Hot reloading re-evaluates modules, so the connection and compiled models
+    // are kept on globalThis / mongoose.models instead of being recreated
+    let connection = r#"import mongoose from 'mongoose'
+
+const MONGODB_URI = process.env.MONGODB_URI
+
+if (!MONGODB_URI) {
+  throw new Error('MONGODB_URI is not set')
+}
+
+type MongooseCache = {
+  conn: typeof mongoose | null
+  promise: Promise<typeof mongoose> | null
+}
+
+const globalForMongoose = globalThis as unknown as { mongoose?: MongooseCache }
+
+const cached = (globalForMongoose.mongoose ??= { conn: null, promise: null })
+
+export async function connectToDatabase() {
+  if (cached.conn) {
+    return cached.conn
+  }
+
+  cached.promise ??= mongoose.connect(MONGODB_URI!, { bufferCommands: false })
+
+  try {
+    cached.conn = await cached.promise
+  } catch (err) {
+    cached.promise = null
+    throw err
+  }
+
+  return cached.conn
+}
+"#;
+
+    let model = r#"import { type InferSchemaType, Schema, model, models } from 'mongoose'
+
+const userSchema = new Schema(
+  {
+    name: { type: String, required: true },
+    email: { type: String, required: true, unique: true },
+  },
+  { timestamps: true },
+)
+
+export type User = InferSchemaType<typeof userSchema>
+
+export const User = models.User ?? model('User', userSchema)
+"#;
+
+    let (connection_path, model_path) = match config.framework {
+        Framework::NextJs | Framework::TanStackStart => ("lib/mongodb.ts", "models/user.ts"),
+        Framework::SolidStart => ("src/lib/mongodb.ts", "src/models/user.ts"),
+        Framework::Hono | Framework::Express | Framework::Fastify => {
+            ("src/db.ts", "src/models/user.ts")
+        }
+        _ => return Ok(()),
+    };
+    fs::write_file(&project_path.join(connection_path), connection)?;
+    fs::write_file(&project_path.join(model_path), model)?;
+
+    Ok(())
+}
@@ -0,0 +1,932 @@
+use crate::config::{
+    Auth, Database, Framework, MonorepoConfig, Orm, PackageManager, ProjectConfig, SqlProvider,
+};
+use crate::templates::{database, docker_compose, drizzle, package_json};
+use crate::utils::fs;
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+use std::path::Path;
+
+/// Generates the workspace root and the shared packages under `packages/`.
+/// Apps are generated separately and wired up with [`link_app`].
+pub fn generate_monorepo_root(config: &MonorepoConfig, root_path: &Path) -> Result<()> {
+    let pm = &config.package_manager;
+
+    // Generate root package.json
+    let mut package_json = json!({
+        "name": config.name,
+        "private": true,
+        "packageManager": pm.package_manager_field(),
+        "scripts": {
+            "build": "turbo run build",
+            "dev": "turbo run dev",
+            "lint": "turbo run lint",
+            "typecheck": "turbo run typecheck",
+            "test": "turbo run test"
+        },
+        "devDependencies": {
+            "turbo": "latest",
+            "typescript": "latest"
+        }
+    });
+
+    // pnpm reads its workspace globs from pnpm-workspace.yaml; npm, yarn and
+    // bun read them from package.json
+    if matches!(pm, PackageManager::Pnpm) {
+        let workspace = "packages:\n  - \"apps/*\"\n  - \"packages/*\"\n";
+        fs::write_file(&root_path.join("pnpm-workspace.yaml"), workspace)?;
+    } else {
+        package_json["workspaces"] = json!(["apps/*", "packages/*"]);
+    }
+
+    for task in db_tasks(config) {
+        package_json["scripts"][*task] = json!(format!("turbo run {}", task));
+    }
+
+    // The apps' local services run from one compose file at the root
+    if has_services(config) {
+        docker_compose::generate_workspace_compose(
+            &config.apps,
+            &postgres_databases(config),
+            root_path,
+        )?;
+        package_json["scripts"]["db:up"] = json!("docker compose up -d --wait");
+        package_json["scripts"]["db:down"] = json!("docker compose down");
+    }
+
+    package_json::write_package_json(root_path, &package_json)?;
+
+    // Generate turbo.json
+    let mut turbo = json!({
+        "$schema": "https://turbo.build/schema.json",
+        "ui": "tui",
+        "tasks": {
+            "build": {
+                "dependsOn": ["^build"],
+                "inputs": ["$TURBO_DEFAULT$", ".env*"],
+                "outputs": [".next/**", "!.next/cache/**", "dist/**", ".output/**"]
+            },
+            "dev": {
+                "cache": false,
+                "persistent": true
+            },
+            "lint": {
+                "dependsOn": ["^lint"]
+            },
+            "typecheck": {
+                "dependsOn": ["^typecheck"]
+            },
+            "test": {
+                "dependsOn": ["^build"]
+            }
+        }
+    });
+
+    for task in db_tasks(config) {
+        turbo["tasks"][*task] = json!({ "cache": false });
+    }
+
+    fs::write_file(
+        &root_path.join("turbo.json"),
+        &serde_json::to_string_pretty(&turbo)?,
+    )?;
+
+    // Generate shared packages
+    generate_config_package(root_path)?;
+
+    if config.uses_shadcn() {
+        generate_ui_package(pm, root_path)?;
+    }
+
+    if let Some(app) = config.drizzle_app() {
+        generate_db_package(pm, app, root_path)?;
+    }
+
+    // Generate README.md
+    let mut readme = format!(
+        "# {}\n\nA Turborepo monorepo generated with cp-cli.\n\n## Getting started\n\n```bash\n{}\n{}\n```\n\n## Layout\n\n",
+        config.name,
+        pm.install_cmd(),
+        pm.dev_cmd(),
+    );
+    for app in &config.apps {
+        readme.push_str(&format!("- `apps/{}` - {}\n", app.name, app.framework));
+    }
+    readme.push_str("- `packages/config` - shared TypeScript and ESLint config\n");
+    if config.uses_shadcn() {
+        readme.push_str("- `packages/ui` - shared shadcn/ui components\n");
+    }
+    if config.drizzle_app().is_some() {
+        readme.push_str("- `packages/db` - shared Drizzle schema and client\n");
+    }
+    readme.push_str(&format!(
+        "\n## Scripts\n\n- `{}` - start every app in dev mode\n- `{}` - build every app and package\n- `{}` - type-check the workspace\n\nRun a single app with `{}`.\n",
+        pm.run_cmd("dev"),
+        pm.run_cmd("build"),
+        pm.run_cmd("typecheck"),
+        pm.exec_cmd("turbo run dev --filter=<app>"),
+    ));
+    if has_services(config) {
+        readme.push_str(&format!(
+            "\n## Local services\n\n`{}` starts the services the apps use from the root `docker-compose.yml`, and `{}` stops them.\n",
+            pm.run_cmd("db:up"),
+            pm.run_cmd("db:down")
+        ));
+    }
+    if config.uses_shadcn() {
+        readme.push_str(&format!(
+            "\n## UI components\n\nAdd shadcn/ui components from an app directory with `{}`; they are written to `packages/ui`.\n",
+            pm.exec_cmd("shadcn@latest add button")
+        ));
+    }
+    fs::write_file(&root_path.join("README.md"), &readme)?;
+
+    // Generate .gitignore
+    let gitignore = r#"# dependencies
+node_modules
+
+# build
+dist
+.next
+.output
+.vinxi
+.expo
+
+# turbo
+.turbo
+
+# misc
+.DS_Store
+*.pem
+
+# debug
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+
+# local env files
+.env*.local
+
+# typescript
+*.tsbuildinfo
+"#;
+    fs::write_file(&root_path.join(".gitignore"), gitignore)?;
+
+    Ok(())
+}
+
+/// Checks that `app` can use the shared `db` package built from
+/// `drizzle_app`. Another provider would import a different dialect's client,
+/// and another auth choice needs other tables than the shared schema has.
+pub fn check_shared_db(drizzle_app: &ProjectConfig, app: &ProjectConfig) -> Result<()> {
+    let (Database::Sql(Orm::Drizzle, shared), Database::Sql(Orm::Drizzle, provider)) =
+        (&drizzle_app.database, &app.database)
+    else {
+        return Ok(());
+    };
+    if provider != shared {
+        anyhow::bail!(
+            "apps/{} uses {} but packages/db is shared with apps/{}, which uses {}",
+            app.name,
+            app.database,
+            drizzle_app.name,
+            drizzle_app.database
+        );
+    }
+    if drizzle::schema(app, provider) != drizzle::schema(drizzle_app, shared) {
+        anyhow::bail!(
+            "apps/{} uses {} auth but packages/db is shared with apps/{}, which uses {}",
+            app.name,
+            app.auth,
+            drizzle_app.name,
+            drizzle_app.auth
+        );
+    }
+    Ok(())
+}
+
+/// Checks that `app` can use the `db` package of an existing workspace, like
+/// [`check_shared_db`] does for apps generated together. The provider is read
+/// from the driver the package depends on and the auth choice from the tables
+/// its schema exports.
+pub fn check_db_package(app: &ProjectConfig, root_path: &Path) -> Result<()> {
+    let Database::Sql(Orm::Drizzle, provider) = &app.database else {
+        return Ok(());
+    };
+    if shared_package(root_path, "db", "./schema").is_none() {
+        return Ok(());
+    }
+    let package_path = root_path.join("packages/db");
+
+    let package_json = package_json::read_package_json(&package_path)?;
+    let dependencies = &package_json["dependencies"];
+    let Some(shared) = Orm::Drizzle.providers().into_iter().find(|provider| {
+        database::driver_packages(provider)
+            .iter()
+            .all(|package| !dependencies[*package].is_null())
+    }) else {
+        anyhow::bail!("packages/db does not depend on a database driver this CLI knows");
+    };
+    if *provider != shared {
+        anyhow::bail!(
+            "{} uses {} but packages/db uses {}",
+            app.name,
+            app.database,
+            Database::Sql(Orm::Drizzle, shared)
+        );
+    }
+
+    let schema_path = package_path.join("src/schema.ts");
+    let schema = std::fs::read_to_string(&schema_path)
+        .with_context(|| format!("Failed to read file: {}", schema_path.display()))?;
+    let auth = if schema.contains("export const verifications ") {
+        Auth::BetterAuth
+    } else if schema.contains("export const verificationTokens ") {
+        Auth::NextAuth
+    } else {
+        Auth::None
+    };
+    let shared_app = ProjectConfig {
+        auth,
+        ..app.clone()
+    };
+    if drizzle::schema(app, provider) != drizzle::schema(&shared_app, provider) {
+        anyhow::bail!(
+            "{} uses {} auth but packages/db has the tables for {} auth",
+            app.name,
+            app.auth,
+            shared_app.auth
+        );
+    }
+    Ok(())
+}
+
+/// Whether any app needs local services from the root `docker-compose.yml`.
+pub fn has_services(config: &MonorepoConfig) -> bool {
+    config.apps.iter().any(docker_compose::has_services)
+}
+
+/// Databases of the shared Postgres server, for the apps running PostgreSQL
+/// in docker-compose. Drizzle apps all use the one `packages/db` connects to.
+fn postgres_databases(config: &MonorepoConfig) -> Vec<String> {
+    let mut databases: Vec<String> = Vec::new();
+    for app in config
+        .apps
+        .iter()
+        .filter(|app| database::docker_postgres(app))
+    {
+        let database = match app.database {
+            Database::Sql(Orm::Drizzle, SqlProvider::Postgres) => {
+                database::database_name(config.drizzle_app().unwrap_or(app))
+            }
+            Database::Sql(_, SqlProvider::Postgres) => database::database_name(app),
+            _ => continue,
+        };
+        if !databases.contains(&database) {
+            databases.push(database);
+        }
+    }
+    databases
+}
+
+/// Scripts of the shared `db` package exposed at the root.
+fn db_tasks(config: &MonorepoConfig) -> &'static [&'static str] {
+    if config.drizzle_app().is_some() {
+        &["db:generate", "db:push", "db:migrate", "db:seed"]
+    } else {
+        &[]
+    }
+}
+
+/// Points an app generated inside a workspace at the shared packages the
+/// workspace provides, replacing its local copies. Packages are looked up by
+/// directory under `packages/` and only used when they expose the entry
+/// points the generated code imports.
+pub fn link_app(app: &ProjectConfig, app_path: &Path, root_path: &Path) -> Result<()> {
+    let workspace_version = app.package_manager.workspace_version();
+    let config_package = shared_package(root_path, "config", "./tsconfig/*");
+    let eslint_package = shared_package(root_path, "config", "./eslint");
+    let ui_package = shared_package(root_path, "ui", "./lib/*").filter(|_| app.use_shadcn);
+    let db_package = shared_package(root_path, "db", "./schema")
+        .filter(|_| matches!(app.database, Database::Sql(Orm::Drizzle, _)));
+
+    // Relative path from the app back to the workspace root
+    let depth = app_path
+        .strip_prefix(root_path)
+        .map_or(2, |p| p.components().count());
+    let to_root = "../".repeat(depth);
+
+    let mut package_json = package_json::read_package_json(app_path)?;
+
+    if let Some(config_package) = &config_package {
+        package_json["devDependencies"][config_package] = json!(workspace_version);
+
+        // Start from the shared base; the app's own options and any
+        // framework base listed after it still take precedence
+        let tsconfig_path = app_path.join("tsconfig.json");
+        if tsconfig_path.exists() {
+            let tsconfig = std::fs::read_to_string(&tsconfig_path)
+                .with_context(|| format!("Failed to read file: {}", tsconfig_path.display()))?;
+            let mut tsconfig: Value = serde_json::from_str(&tsconfig)
+                .with_context(|| format!("Failed to parse {}", tsconfig_path.display()))?;
+            let base = json!(format!("{}/tsconfig/base.json", config_package));
+            tsconfig["extends"] = match tsconfig["extends"].take() {
+                Value::Null => base,
+                Value::Array(mut extends) => {
+                    extends.insert(0, base);
+                    Value::Array(extends)
+                }
+                framework_base => json!([base, framework_base]),
+            };
+            fs::write_file(&tsconfig_path, &serde_json::to_string_pretty(&tsconfig)?)?;
+        }
+    }
+
+    if let Some(eslint_package) = &eslint_package {
+        let has_eslint_config = ["eslint.config.js", "eslint.config.mjs"]
+            .iter()
+            .any(|file| app_path.join(file).exists());
+        if !has_eslint_config {
+            fs::write_file(
+                &app_path.join("eslint.config.js"),
+                format!("export {{ default }} from '{}/eslint'\n", eslint_package),
+            )?;
+            if package_json["devDependencies"]["eslint"].is_null() {
+                package_json["devDependencies"]["eslint"] = json!("latest");
+            }
+            if package_json["scripts"]["lint"].is_null() {
+                package_json["scripts"]["lint"] = json!("eslint .");
+            }
+        }
+    }
+
+    if let Some(ui_package) = &ui_package {
+        package_json["dependencies"][ui_package] = json!(workspace_version);
+
+        let components_json = json!({
+            "$schema": "https://ui.shadcn.com/schema.json",
+            "style": "new-york",
+            "rsc": matches!(app.framework, Framework::NextJs),
+            "tsx": true,
+            "tailwind": {
+                "config": "tailwind.config.js",
+                "css": format!("{}packages/ui/src/styles/globals.css", to_root),
+                "baseColor": "neutral",
+                "cssVariables": true
+            },
+            "aliases": {
+                "components": "@/components",
+                "hooks": "@/hooks",
+                "lib": "@/lib",
+                "utils": format!("{}/lib/utils", ui_package),
+                "ui": format!("{}/components", ui_package)
+            }
+        });
+        fs::write_file(
+            &app_path.join("components.json"),
+            &serde_json::to_string_pretty(&components_json)?,
+        )?;
+
+        // Let Tailwind see the classes used by the shared components
+        let tailwind_path = app_path.join("tailwind.config.js");
+        if tailwind_path.exists() {
+            let mut tailwind_config = std::fs::read_to_string(&tailwind_path)
+                .with_context(|| format!("Failed to read file: {}", tailwind_path.display()))?;
+            let ui_glob = format!("'{}packages/ui/src/**/*.{{ts,tsx}}',", to_root);
+            let content = "content: [";
+            let Some(at) = tailwind_config.find(content).map(|i| i + content.len()) else {
+                anyhow::bail!("No `{}` found in {}", content, tailwind_path.display());
+            };
+            let entry = if tailwind_config[at..].starts_with('\n') {
+                format!("\n    {}", ui_glob)
+            } else {
+                format!("{} ", ui_glob)
+            };
+            tailwind_config.insert_str(at, &entry);
+            fs::write_file(&tailwind_path, &tailwind_config)?;
+        }
+
+        let lib_utils = app_path.join("lib/utils.ts");
+        if lib_utils.exists() {
+            fs::write_file(
+                &lib_utils,
+                format!("export {{ cn }} from '{}/lib/utils'\n", ui_package),
+            )?;
+        }
+    }
+
+    if let Some(db_package) = &db_package {
+        package_json["dependencies"][db_package] = json!(workspace_version);
+        if let (Some(deps), Database::Sql(_, provider)) =
+            (package_json["dependencies"].as_object_mut(), &app.database)
+        {
+            for package in database::driver_packages(provider) {
+                deps.remove(*package);
+            }
+            deps.remove("drizzle-kit");
+        }
+
+        // Keep the app's `db` imports working by re-exporting the shared package
+        fs::write_file(
+            &app_path.join("db/schema.ts"),
+            format!("export * from '{}/schema'\n", db_package),
+        )?;
+        if app_path.join("db/index.ts").exists() {
+            fs::write_file(
+                &app_path.join("db/index.ts"),
+                format!("export * from '{}'\n", db_package),
+            )?;
+        }
+        if app_path.join("drizzle.config.ts").exists() {
+            std::fs::remove_file(app_path.join("drizzle.config.ts"))
+                .with_context(|| "Failed to remove the app's drizzle.config.ts")?;
+        }
+
+        // Migrations and seeding run from the shared package; the app keeps
+        // the scripts for its own docker-compose.yml, see [`link_services`]
+        if let Some(scripts) = package_json["scripts"].as_object_mut() {
+            scripts
+                .retain(|name, _| !name.starts_with("db:") || name == "db:up" || name == "db:down");
+        }
+        if app_path.join("db/seed.ts").exists() {
+            std::fs::remove_file(app_path.join("db/seed.ts"))
+                .with_context(|| "Failed to remove the app's db/seed.ts")?;
+        }
+        if app_path.join("drizzle").exists() {
+            std::fs::remove_dir_all(app_path.join("drizzle"))
+                .with_context(|| "Failed to remove the app's drizzle folder")?;
+        }
+
+        // Local database files live next to the shared package, and a local
+        // Postgres server is used through the package's database
+        let env_path = app_path.join(".env.local");
+        if env_path.exists() {
+            let mut env = std::fs::read_to_string(&env_path)
+                .with_context(|| format!("Failed to read file: {}", env_path.display()))?;
+            if let Database::Sql(_, SqlProvider::Postgres) = app.database {
+                let shared_env = std::fs::read_to_string(root_path.join("packages/db/.env.local"))
+                    .unwrap_or_default();
+                let url_line = |env: &str| {
+                    env.lines()
+                        .find(|line| line.starts_with("DATABASE_URL="))
+                        .map(String::from)
+                };
+                if let (Some(url), Some(shared_url)) = (url_line(&env), url_line(&shared_env)) {
+                    env = env.replacen(&url, &shared_url, 1);
+                }
+            }
+            let local_db = format!("{}packages/db/local.db", to_root);
+            let env = env
+                .replace(
+                    "DATABASE_URL=file:local.db",
+                    &format!("DATABASE_URL=file:{}", local_db),
+                )
+                .replace(
+                    "DATABASE_URL=local.db",
+                    &format!("DATABASE_URL={}", local_db),
+                );
+            fs::write_file(&env_path, &env)?;
+        }
+    }
+
+    package_json::write_package_json(app_path, &package_json)?;
+
+    // Next.js only compiles workspace packages listed in transpilePackages
+    let next_config_path = app_path.join("next.config.js");
+    if matches!(app.framework, Framework::NextJs) && next_config_path.exists() {
+        let packages: Vec<String> = [&ui_package, &db_package]
+            .into_iter()
+            .flatten()
+            .map(|name| format!("'{}'", name))
+            .collect();
+        if !packages.is_empty() {
+            let next_config = std::fs::read_to_string(&next_config_path)
+                .with_context(|| format!("Failed to read file: {}", next_config_path.display()))?;
+            let empty_config = "const nextConfig = {}";
+            if !next_config.contains(empty_config) {
+                anyhow::bail!(
+                    "No `{}` found in {}",
+                    empty_config,
+                    next_config_path.display()
+                );
+            }
+            let next_config = next_config.replacen(
+                empty_config,
+                &format!(
+                    "const nextConfig = {{\n  transpilePackages: [{}],\n}}",
+                    packages.join(", ")
+                ),
+                1,
+            );
+            fs::write_file(&next_config_path, &next_config)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves an app generated with the workspace onto the root
+/// `docker-compose.yml`, dropping its own so host ports aren't bound twice.
+pub fn link_services(app: &ProjectConfig, app_path: &Path) -> Result<()> {
+    if !docker_compose::has_services(app) {
+        return Ok(());
+    }
+
+    std::fs::remove_file(app_path.join("docker-compose.yml"))
+        .with_context(|| "Failed to remove the app's docker-compose.yml")?;
+
+    let mut package_json = package_json::read_package_json(app_path)?;
+    if let Some(scripts) = package_json["scripts"].as_object_mut() {
+        scripts.remove("db:up");
+        scripts.remove("db:down");
+    }
+    package_json::write_package_json(app_path, &package_json)
+}
+
+/// Name of the workspace package in `packages/<dir>`, if it exists and
+/// exports `entry`.
+fn shared_package(root_path: &Path, dir: &str, entry: &str) -> Option<String> {
+    let package_path = root_path.join("packages").join(dir);
+    let package_json = package_json::read_package_json(&package_path).ok()?;
+    package_json["exports"].get(entry)?;
+    package_json["name"].as_str().map(String::from)
+}
+
+fn generate_config_package(root_path: &Path) -> Result<()> {
+    let package_path = root_path.join("packages/config");
+
+    let package_json = json!({
+        "name": "@repo/config",
+        "version": "0.0.0",
+        "private": true,
+        "type": "module",
+        "exports": {
+            "./tsconfig/*": "./tsconfig/*",
+            "./eslint": "./eslint.config.js"
+        },
+        "devDependencies": {
+            "@eslint/js": "latest",
+            "eslint": "latest",
+            "typescript-eslint": "latest"
+        }
+    });
+    package_json::write_package_json(&package_path, &package_json)?;
+
+    let base = json!({
+        "$schema": "https://json.schemastore.org/tsconfig",
+        "compilerOptions": {
+            "target": "ES2022",
+            "lib": ["ES2022"],
+            "module": "esnext",
+            "moduleResolution": "bundler",
+            "strict": true,
+            "skipLibCheck": true,
+            "esModuleInterop": true,
+            "resolveJsonModule": true,
+            "isolatedModules": true,
+            "noEmit": true
+        }
+    });
+    fs::write_file(
+        &package_path.join("tsconfig/base.json"),
+        &serde_json::to_string_pretty(&base)?,
+    )?;
+
+    let react_library = json!({
+        "$schema": "https://json.schemastore.org/tsconfig",
+        "extends": "./base.json",
+        "compilerOptions": {
+            "lib": ["ES2022", "DOM", "DOM.Iterable"],
+            "jsx": "react-jsx"
+        }
+    });
+    fs::write_file(
+        &package_path.join("tsconfig/react-library.json"),
+        &serde_json::to_string_pretty(&react_library)?,
+    )?;
+
+    let eslint_config = r#"import js from '@eslint/js'
+import tseslint from 'typescript-eslint'
+
+export default tseslint.config(
+  {
+    ignores: [
+      'dist',
+      '.next',
+      '.output',
+      '.turbo',
+      '.vinxi',
+      '.expo',
+      '.wxt',
+      '.astro',
+      '.source',
+      'src-tauri',
+    ],
+  },
+  js.configs.recommended,
+  ...tseslint.configs.recommended,
+)
+"#;
+    fs::write_file(&package_path.join("eslint.config.js"), eslint_config)?;
+
+    Ok(())
+}
+
+fn generate_ui_package(pm: &PackageManager, root_path: &Path) -> Result<()> {
+    let package_path = root_path.join("packages/ui");
+
+    let package_json = json!({
+        "name": "@repo/ui",
+        "version": "0.0.0",
+        "private": true,
+        "type": "module",
+        "exports": {
+            "./components/*": "./src/components/*.tsx",
+            "./lib/*": "./src/lib/*.ts",
+            "./styles/*": "./src/styles/*"
+        },
+        "scripts": {
+            "lint": "eslint .",
+            "typecheck": "tsc --noEmit"
+        },
+        "dependencies": {
+            "@radix-ui/react-slot": "latest",
+            "class-variance-authority": "latest",
+            "clsx": "latest",
+            "lucide-react": "latest",
+            "tailwind-merge": "latest"
+        },
+        "peerDependencies": {
+            "react": "*"
+        },
+        "devDependencies": {
+            "@repo/config": pm.workspace_version(),
+            "@types/react": "latest",
+            "eslint": "latest",
+            "tailwindcss": "^3",
+            "typescript": "latest"
+        }
+    });
+    package_json::write_package_json(&package_path, &package_json)?;
+
+    let tsconfig = json!({
+        "extends": "@repo/config/tsconfig/react-library.json",
+        "compilerOptions": {
+            "baseUrl": ".",
+            "paths": {
+                "@repo/ui/*": ["./src/*"]
+            }
+        },
+        "include": ["src"]
+    });
+    fs::write_file(
+        &package_path.join("tsconfig.json"),
+        &serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+
+    fs::write_file(
+        &package_path.join("eslint.config.js"),
+        "export { default } from '@repo/config/eslint'\n",
+    )?;
+
+    let components_json = json!({
+        "$schema": "https://ui.shadcn.com/schema.json",
+        "style": "new-york",
+        "rsc": true,
+        "tsx": true,
+        "tailwind": {
+            "config": "",
+            "css": "src/styles/globals.css",
+            "baseColor": "neutral",
+            "cssVariables": true
+        },
+        "aliases": {
+            "components": "@repo/ui/components",
+            "utils": "@repo/ui/lib/utils",
+            "hooks": "@repo/ui/hooks",
+            "lib": "@repo/ui/lib",
+            "ui": "@repo/ui/components"
+        }
+    });
+    fs::write_file(
+        &package_path.join("components.json"),
+        &serde_json::to_string_pretty(&components_json)?,
+    )?;
+
+    let globals_css = r#"@tailwind base;
+@tailwind components;
+@tailwind utilities;
+"#;
+    fs::write_file(&package_path.join("src/styles/globals.css"), globals_css)?;
+
+    let utils = r#"import { clsx, type ClassValue } from 'clsx'
+import { twMerge } from 'tailwind-merge'
+
+export function cn(...inputs: ClassValue[]) {
+  return twMerge(clsx(inputs))
+}
+"#;
+    fs::write_file(&package_path.join("src/lib/utils.ts"), utils)?;
+
+    let button = r#"import * as React from 'react'
+import { Slot } from '@radix-ui/react-slot'
+import { cva, type VariantProps } from 'class-variance-authority'
+
+import { cn } from '@repo/ui/lib/utils'
+
+const buttonVariants = cva(
+  'inline-flex items-center justify-center gap-2 whitespace-nowrap rounded-md text-sm font-medium transition-colors disabled:pointer-events-none disabled:opacity-50',
+  {
+    variants: {
+      variant: {
+        default: 'bg-neutral-900 text-neutral-50 hover:bg-neutral-900/90',
+        outline: 'border border-neutral-200 bg-white hover:bg-neutral-100',
+        ghost: 'hover:bg-neutral-100',
+      },
+      size: {
+        default: 'h-9 px-4 py-2',
+        sm: 'h-8 rounded-md px-3 text-xs',
+        lg: 'h-10 rounded-md px-8',
+      },
+    },
+    defaultVariants: {
+      variant: 'default',
+      size: 'default',
+    },
+  },
+)
+
+export interface ButtonProps
+  extends React.ButtonHTMLAttributes<HTMLButtonElement>,
+    VariantProps<typeof buttonVariants> {
+  asChild?: boolean
+}
+
+export function Button({ className, variant, size, asChild = false, ...props }: ButtonProps) {
+  const Comp = asChild ? Slot : 'button'
+  return <Comp className={cn(buttonVariants({ variant, size, className }))} {...props} />
+}
+
+export { buttonVariants }
+"#;
+    fs::write_file(&package_path.join("src/components/button.tsx"), button)?;
+
+    Ok(())
+}
+
+fn generate_db_package(pm: &PackageManager, app: &ProjectConfig, root_path: &Path) -> Result<()> {
+    let Database::Sql(_, provider) = &app.database else {
+        return Ok(());
+    };
+    let package_path = root_path.join("packages/db");
+
+    let mut package_json = json!({
+        "name": "@repo/db",
+        "version": "0.0.0",
+        "private": true,
+        "type": "module",
+        "exports": {
+            ".": "./src/index.ts",
+            "./schema": "./src/schema.ts"
+        },
+        "scripts": {
+            "typecheck": "tsc --noEmit"
+        },
+        "dependencies": {
+            "drizzle-orm": "latest"
+        },
+        "devDependencies": {
+            "@repo/config": pm.workspace_version(),
+            "drizzle-kit": "latest",
+            "typescript": "latest"
+        }
+    });
+    for package in database::driver_packages(provider) {
+        package_json["dependencies"][*package] = json!("latest");
+    }
+    for package in database::driver_type_packages(provider) {
+        package_json["devDependencies"][*package] = json!("latest");
+    }
+    drizzle::add_scripts("src/seed.ts", &mut package_json);
+    package_json::write_package_json(&package_path, &package_json)?;
+
+    let tsconfig = json!({
+        "extends": "@repo/config/tsconfig/base.json",
+        "include": ["src", "drizzle.config.ts"]
+    });
+    fs::write_file(
+        &package_path.join("tsconfig.json"),
+        &serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+
+    fs::write_file(
+        &package_path.join("src/schema.ts"),
+        drizzle::schema(app, provider),
+    )?;
+    fs::write_file(
+        &package_path.join("src/index.ts"),
+        drizzle::client(provider),
+    )?;
+    fs::write_file(
+        &package_path.join("drizzle.config.ts"),
+        drizzle::config(provider, "./src/schema.ts"),
+    )?;
+
+    fs::write_file(&package_path.join("src/seed.ts"), drizzle::SEED)?;
+    fs::write_file(&package_path.join("drizzle/.gitkeep"), "")?;
+    let gitignore = database::gitignore_entries(app);
+    if !gitignore.is_empty() {
+        fs::write_file(&package_path.join(".gitignore"), gitignore.trim_start())?;
+    }
+
+    let mut env_content = String::new();
+    for line in database::env_vars(app) {
+        env_content.push_str(&line);
+        env_content.push('\n');
+    }
+    fs::write_file(&package_path.join(".env.local"), &env_content)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::fixtures;
+
+    #[test]
+    fn check_shared_db_accepts_matching_apps() {
+        let web = ProjectConfig {
+            database: Database::Sql(Orm::Drizzle, SqlProvider::Neon),
+            ..fixtures::project("web")
+        };
+        let admin = ProjectConfig {
+            auth: Auth::Clerk,
+            ..web.clone()
+        };
+        let api = ProjectConfig {
+            database: Database::MongoDB,
+            ..fixtures::project("api")
+        };
+        assert!(check_shared_db(&web, &admin).is_ok());
+        assert!(check_shared_db(&web, &api).is_ok());
+    }
+
+    #[test]
+    fn check_shared_db_rejects_another_provider() {
+        let web = ProjectConfig {
+            database: Database::Sql(Orm::Drizzle, SqlProvider::Neon),
+            ..fixtures::project("web")
+        };
+        let admin = ProjectConfig {
+            database: Database::Sql(Orm::Drizzle, SqlProvider::Sqlite),
+            ..fixtures::project("admin")
+        };
+        assert!(check_shared_db(&web, &admin).is_err());
+    }
+
+    #[test]
+    fn check_db_package_reads_the_existing_package() {
+        let root = fixtures::temp_root("check-db-package");
+        let web = ProjectConfig {
+            database: Database::Sql(Orm::Drizzle, SqlProvider::Postgres),
+            auth: Auth::BetterAuth,
+            ..fixtures::project("web")
+        };
+        generate_db_package(&web.package_manager, &web, &root).unwrap();
+
+        let admin = ProjectConfig {
+            name: "admin".to_string(),
+            ..web.clone()
+        };
+        assert!(check_db_package(&admin, &root).is_ok());
+        let sqlite = ProjectConfig {
+            database: Database::Sql(Orm::Drizzle, SqlProvider::Sqlite),
+            ..admin.clone()
+        };
+        assert!(check_db_package(&sqlite, &root).is_err());
+        for auth in [Auth::None, Auth::NextAuth] {
+            let admin = ProjectConfig {
+                auth,
+                ..admin.clone()
+            };
+            assert!(check_db_package(&admin, &root).is_err());
+        }
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn check_shared_db_rejects_other_auth_tables() {
+        let web = ProjectConfig {
+            database: Database::Sql(Orm::Drizzle, SqlProvider::Neon),
+            ..fixtures::project("web")
+        };
+        for auth in [Auth::NextAuth, Auth::BetterAuth] {
+            let admin = ProjectConfig {
+                auth,
+                ..web.clone()
+            };
+            assert!(check_shared_db(&web, &admin).is_err());
+        }
+    }
+}
@@ -1,11 +1,11 @@
-use crate::config::ProjectConfig;
+use crate::config::{Auth, Database, ProjectConfig};
+use crate::templates::{authjs, better_auth, database, package_json, redis};
 use crate::utils::fs;
 use anyhow::Result;
 use serde_json::json;
 use std::path::Path;
 
 pub fn generate_nextjs_project(config: &ProjectConfig, project_path: &Path) -> Result<()> {
-
     // Generate package.json
     let mut deps = serde_json::Map::new();
     deps.insert("next".to_string(), json!("latest"));
@@ -18,23 +18,19 @@ pub fn generate_nextjs_project(config: &ProjectConfig, project_path: &Path) -> R
         deps.insert("autoprefixer".to_string(), json!("latest"));
     }
 
-    if config.use_clerk {
-        deps.insert("@clerk/nextjs".to_string(), json!("latest"));
-    }
-
-    match config.database {
-        crate::config::Database::Convex => {
-            deps.insert("convex".to_string(), json!("latest"));
-        }
-        crate::config::Database::NeonDrizzle => {
-            deps.insert("drizzle-orm".to_string(), json!("latest"));
-            deps.insert("@neondatabase/serverless".to_string(), json!("latest"));
-            deps.insert("drizzle-kit".to_string(), json!("latest"));
+    match config.auth {
+        Auth::Clerk => {
+            deps.insert("@clerk/nextjs".to_string(), json!("latest"));
         }
-        crate::config::Database::None => {}
+        Auth::NextAuth => authjs::add_dependencies(config, &mut deps),
+        Auth::BetterAuth => better_auth::add_dependencies(config, &mut deps),
+        Auth::None => {}
     }
 
-    let package_json = json!({
+    database::add_dependencies(config, &mut deps);
+    redis::add_dependencies(config, &mut deps);
+
+    let mut package_json = json!({
         "name": config.name,
         "version": "0.1.0",
         "private": true,
@@ -55,10 +51,9 @@ pub fn generate_nextjs_project(config: &ProjectConfig, project_path: &Path) -> R
         }
     });
 
-    fs::write_file(
-        &project_path.join("package.json"),
-        &serde_json::to_string_pretty(&package_json)?,
-    )?;
+    database::add_scripts(config, &mut package_json);
+
+    package_json::write_package_json(project_path, &package_json)?;
 
     // Generate tsconfig.json
     let tsconfig = json!({
@@ -105,9 +100,11 @@ module.exports = nextConfig
     // Create app directory structure
     fs::create_directory(&project_path.join("app"))?;
 
-    // Generate app/layout.tsx
+    // Generate app/layout.tsx, wrapping the page in a client-side provider
+    // for Convex
+    let use_convex = matches!(config.database, Database::Convex);
     let layout_content = r#"import type { Metadata } from 'next'
-import './globals.css'
+{{IMPORTS}}import './globals.css'
 
 export const metadata: Metadata = {
   title: '{{PROJECT_NAME}}',
@@ -121,14 +118,66 @@ export default function RootLayout({
 }) {
   return (
     <html lang="en">
-      <body>{children}</body>
+      {{BODY}}
     </html>
   )
 }
 "#;
-    let layout_content = layout_content.replace("{{PROJECT_NAME}}", &config.name);
+    let (imports, body) = if use_convex {
+        (
+            "import { ConvexClientProvider } from './ConvexClientProvider'\n",
+            "<body>\n        <ConvexClientProvider>{children}</ConvexClientProvider>\n      </body>",
+        )
+    } else {
+        ("", "<body>{children}</body>")
+    };
+    let layout_content = layout_content
+        .replace("{{PROJECT_NAME}}", &config.name)
+        .replace("{{IMPORTS}}", imports)
+        .replace("{{BODY}}", body);
     fs::write_file(&project_path.join("app/layout.tsx"), &layout_content)?;
 
+    if use_convex {
+        // With Clerk, Convex authenticates with the Clerk session's token
+        let provider_content = if let Auth::Clerk = config.auth {
+            r#"'use client'
+
+import { ClerkProvider, useAuth } from '@clerk/nextjs'
+import { ConvexReactClient } from 'convex/react'
+import { ConvexProviderWithClerk } from 'convex/react-clerk'
+import type { ReactNode } from 'react'
+
+const convex = new ConvexReactClient(process.env.NEXT_PUBLIC_CONVEX_URL!)
+
+export function ConvexClientProvider({ children }: { children: ReactNode }) {
+  return (
+    <ClerkProvider>
+      <ConvexProviderWithClerk client={convex} useAuth={useAuth}>
+        {children}
+      </ConvexProviderWithClerk>
+    </ClerkProvider>
+  )
+}
+"#
+        } else {
+            r#"'use client'
+
+import { ConvexProvider, ConvexReactClient } from 'convex/react'
+import type { ReactNode } from 'react'
+
+const convex = new ConvexReactClient(process.env.NEXT_PUBLIC_CONVEX_URL!)
+
+export function ConvexClientProvider({ children }: { children: ReactNode }) {
+  return <ConvexProvider client={convex}>{children}</ConvexProvider>
+}
+"#
+        };
+        fs::write_file(
+            &project_path.join("app/ConvexClientProvider.tsx"),
+            provider_content,
+        )?;
+    }
+
     // Generate app/page.tsx
     let page_content = r#"export default function Home() {
   return (
@@ -185,63 +234,17 @@ module.exports = {
         fs::write_file(&project_path.join("postcss.config.js"), postcss_config)?;
     }
 
-    // Add Clerk middleware if needed
-    if config.use_clerk {
-        let middleware_content = r#"import { clerkMiddleware } from '@clerk/nextjs/server'
-
-export default clerkMiddleware()
-
-export const config = {
-  matcher: [
-    '/((?!_next|[^?]*\\.(?:html?|css|js(?!on)|jpe?g|webp|png|gif|svg|ttf|woff2?|ico|csv|docx?|xlsx?|zip|webmanifest)).*)',
-    '/(api|trpc)(.*)',
-  ],
-}
-"#;
-        fs::write_file(&project_path.join("middleware.ts"), middleware_content)?;
-    }
+    // Add middleware if needed
+    generate_middleware(config, project_path)?;
 
     // Add database setup if needed
-    match config.database {
-        crate::config::Database::Convex => {
-            fs::create_directory(&project_path.join("convex"))?;
-            let schema_content = r#"import { defineSchema, defineTable } from "convex/server";
-import { v } from "convex/values";
-
-export default defineSchema({
-  // Define your tables here
-  // example: exampleTable: defineTable({ name: v.string() }),
-});
-"#;
-            fs::write_file(&project_path.join("convex/schema.ts"), schema_content)?;
-        }
-        crate::config::Database::NeonDrizzle => {
-            fs::create_directory(&project_path.join("db"))?;
-            let schema_content = r#"import { pgTable, serial, text, timestamp } from 'drizzle-orm/pg-core';
-
-export const users = pgTable('users', {
-  id: serial('id').primaryKey(),
-  name: text('name').notNull(),
-  email: text('email').notNull(),
-  createdAt: timestamp('created_at').defaultNow(),
-});
-"#;
-            fs::write_file(&project_path.join("db/schema.ts"), schema_content)?;
-
-            let drizzle_config = r#"import type { Config } from 'drizzle-kit';
+    database::generate_database_files(config, project_path)?;
+    redis::generate_redis_files(config, project_path)?;
 
-export default {
-  schema: './db/schema.ts',
-  out: './drizzle',
-  driver: 'pg',
-  dbCredentials: {
-    connectionString: process.env.DATABASE_URL!,
-  },
-} satisfies Config;
-"#;
-            fs::write_file(&project_path.join("drizzle.config.ts"), drizzle_config)?;
-        }
-        crate::config::Database::None => {}
+    match config.auth {
+        Auth::NextAuth => authjs::generate_authjs_files(config, project_path)?,
+        Auth::BetterAuth => better_auth::generate_better_auth_files(config, project_path)?,
+        Auth::Clerk | Auth::None => {}
     }
 
     // Generate .gitignore
@@ -279,20 +282,130 @@ yarn-error.log*
 *.tsbuildinfo
 next-env.d.ts
 "#;
-    fs::write_file(&project_path.join(".gitignore"), gitignore)?;
+    let gitignore = format!("{}{}", gitignore, database::gitignore_entries(config));
+    fs::write_file(&project_path.join(".gitignore"), &gitignore)?;
 
     // Generate .env.local if needed
-    if config.use_clerk || matches!(config.database, crate::config::Database::NeonDrizzle) {
+    let mut env = match config.auth {
+        Auth::Clerk => vec![
+            "NEXT_PUBLIC_CLERK_PUBLISHABLE_KEY=".to_string(),
+            "CLERK_SECRET_KEY=".to_string(),
+        ],
+        Auth::NextAuth => authjs::env_vars()?,
+        Auth::BetterAuth => better_auth::env_vars()?,
+        Auth::None => Vec::new(),
+    };
+    env.extend(database::env_vars(config));
+    env.extend(redis::env_vars(config));
+    if !env.is_empty() {
         let mut env_content = String::new();
-        if config.use_clerk {
-            env_content.push_str("NEXT_PUBLIC_CLERK_PUBLISHABLE_KEY=\n");
-            env_content.push_str("CLERK_SECRET_KEY=\n");
-        }
-        if matches!(config.database, crate::config::Database::NeonDrizzle) {
-            env_content.push_str("DATABASE_URL=\n");
+        for line in &env {
+            env_content.push_str(line);
+            env_content.push('\n');
         }
         fs::write_file(&project_path.join(".env.local"), &env_content)?;
     }
 
     Ok(())
 }
+
+/// Writes `middleware.ts`, combining the auth provider, the Supabase session
+/// refresh and the Redis rate limit. Clerk or Auth.js wraps the handler when
+/// selected; Supabase auth is skipped alongside them since they own the
+/// session. Better Auth checks sessions in its route handler, so it adds
+/// nothing here.
+fn generate_middleware(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    // The auth wrappers type `request` themselves
+    let wrapped = matches!(config.auth, Auth::Clerk | Auth::NextAuth);
+    let use_supabase =
+        matches!(config.database, Database::Supabase) && matches!(config.auth, Auth::None);
+    let use_ratelimit = redis::limits_in_middleware(config);
+    if !wrapped && !use_supabase && !use_ratelimit {
+        return Ok(());
+    }
+
+    let mut imports = Vec::new();
+    match config.auth {
+        Auth::Clerk => imports.push("import { clerkMiddleware } from '@clerk/nextjs/server'"),
+        Auth::NextAuth => {
+            imports.push("import NextAuth from 'next-auth'");
+            imports.push("import authConfig from './auth.config'");
+        }
+        Auth::BetterAuth | Auth::None => {}
+    }
+    match (use_ratelimit, wrapped) {
+        (true, true) => imports.push("import { NextResponse } from 'next/server'"),
+        (true, false) => {
+            imports.push("import { NextResponse, type NextRequest } from 'next/server'")
+        }
+        (false, true) => {}
+        (false, false) => imports.push("import { type NextRequest } from 'next/server'"),
+    }
+    if use_ratelimit {
+        imports.push("import { ratelimit } from '@/lib/ratelimit'");
+    }
+    if use_supabase {
+        imports.push("import { updateSession } from '@/lib/supabase/middleware'");
+    }
+
+    let mut body = String::new();
+    if use_ratelimit {
+        body.push_str(redis::MIDDLEWARE_RATE_LIMIT);
+    }
+
+    let handler = match config.auth {
+        Auth::Clerk if body.is_empty() => "export default clerkMiddleware()\n".to_string(),
+        Auth::Clerk => format!(
+            "export default clerkMiddleware(async (_auth, request) => {{\n{}}})\n",
+            body
+        ),
+        // Built from the edge-safe config; auth.ts adds the database adapter
+        Auth::NextAuth => {
+            let setup = "const { auth } = NextAuth(authConfig)\n\n";
+            if body.is_empty() {
+                format!("{}export default auth\n", setup)
+            } else {
+                format!(
+                    "{}export default auth(async (request) => {{\n{}}})\n",
+                    setup, body
+                )
+            }
+        }
+        Auth::BetterAuth | Auth::None => {
+            let response = if use_supabase {
+                "await updateSession(request)"
+            } else {
+                "NextResponse.next()"
+            };
+            if !body.is_empty() {
+                body.push('\n');
+            }
+            format!(
+                "export async function middleware(request: NextRequest) {{\n{}  return {}\n}}\n",
+                body, response
+            )
+        }
+    };
+
+    let matcher = match config.auth {
+        Auth::Clerk => {
+            r#"    '/((?!_next|[^?]*\\.(?:html?|css|js(?!on)|jpe?g|webp|png|gif|svg|ttf|woff2?|ico|csv|docx?|xlsx?|zip|webmanifest)).*)',
+    '/(api|trpc)(.*)',"#
+        }
+        Auth::NextAuth => "    '/((?!_next/static|_next/image|favicon.ico).*)',",
+        Auth::None if use_supabase => {
+            r#"    '/((?!_next/static|_next/image|favicon.ico|.*\\.(?:svg|png|jpg|jpeg|gif|webp)$).*)',"#
+        }
+        Auth::BetterAuth | Auth::None => "    '/api/:path*',",
+    };
+
+    let middleware = format!(
+        "{}\n\n{}\nexport const config = {{\n  matcher: [\n{}\n  ],\n}}\n",
+        imports.join("\n"),
+        handler,
+        matcher
+    );
+    fs::write_file(&project_path.join("middleware.ts"), &middleware)?;
+
+    Ok(())
+}
@@ -0,0 +1,410 @@
+use crate::config::{Database, Framework, Orm, ProjectConfig};
+use crate::templates::{database, package_json};
+use crate::utils::fs;
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+pub fn generate_node_server_project(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    let use_drizzle = matches!(config.database, Database::Sql(Orm::Drizzle, _));
+    let is_express = matches!(config.framework, Framework::Express);
+
+    // Generate package.json
+    let mut deps = serde_json::Map::new();
+
+    let mut dev_deps = serde_json::Map::new();
+    dev_deps.insert("typescript".to_string(), json!("latest"));
+    dev_deps.insert("@types/node".to_string(), json!("latest"));
+    dev_deps.insert("tsx".to_string(), json!("latest"));
+    dev_deps.insert("tsup".to_string(), json!("latest"));
+    dev_deps.insert("pino-pretty".to_string(), json!("latest"));
+
+    if is_express {
+        deps.insert("express".to_string(), json!("latest"));
+        deps.insert("pino".to_string(), json!("latest"));
+        deps.insert("pino-http".to_string(), json!("latest"));
+        dev_deps.insert("@types/express".to_string(), json!("latest"));
+    } else {
+        deps.insert("fastify".to_string(), json!("latest"));
+    }
+
+    database::add_dependencies(config, &mut deps);
+
+    let mut package_json = json!({
+        "name": config.name,
+        "version": "0.1.0",
+        "private": true,
+        "type": "module",
+        "scripts": {
+            "dev": "tsx watch --env-file=.env.local src/index.ts",
+            "build": "tsup",
+            "start": "node dist/index.js",
+            "typecheck": "tsc --noEmit"
+        },
+        "dependencies": deps,
+        "devDependencies": dev_deps
+    });
+
+    database::add_scripts(config, &mut package_json);
+
+    package_json::write_package_json(project_path, &package_json)?;
+
+    // Generate tsconfig.json
+    let tsconfig = json!({
+        "compilerOptions": {
+            "target": "ES2022",
+            "lib": ["esnext"],
+            "skipLibCheck": true,
+            "strict": true,
+            "noEmit": true,
+            "esModuleInterop": true,
+            "module": "esnext",
+            "moduleResolution": "bundler",
+            "resolveJsonModule": true,
+            "isolatedModules": true,
+            "types": ["node"]
+        },
+        "include": ["src/**/*.ts", "db/**/*.ts"],
+        "exclude": ["node_modules", "dist"]
+    });
+
+    fs::write_file(
+        &project_path.join("tsconfig.json"),
+        &serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+
+    // Generate tsup.config.ts
+    let tsup_config = r#"import { defineConfig } from 'tsup'
+
+export default defineConfig({
+  entry: ['src/index.ts'],
+  format: ['esm'],
+  target: 'node20',
+  sourcemap: true,
+  clean: true,
+})
+"#;
+    fs::write_file(&project_path.join("tsup.config.ts"), tsup_config)?;
+
+    // Create src directory structure
+    fs::create_directory(&project_path.join("src"))?;
+
+    // Generate src/logger.ts (Fastify has its own pino logger)
+    if is_express {
+        let logger_content = r#"import pino from 'pino'
+
+export const logger = pino({
+  level: process.env.LOG_LEVEL ?? 'info',
+  transport:
+    process.env.NODE_ENV === 'production'
+      ? undefined
+      : { target: 'pino-pretty', options: { colorize: true } },
+})
+"#;
+        fs::write_file(&project_path.join("src/logger.ts"), logger_content)?;
+    }
+
+    // Generate src/errors.ts
+    let errors_content = r#"export class HttpError extends Error {
+  constructor(
+    public readonly status: number,
+    message: string,
+  ) {
+    super(message)
+    this.name = 'HttpError'
+  }
+}
+"#;
+    fs::write_file(&project_path.join("src/errors.ts"), errors_content)?;
+
+    // Generate src/app.ts
+    let app_content = if is_express {
+        express_app(use_drizzle)
+    } else {
+        fastify_app(use_drizzle)
+    };
+    fs::write_file(&project_path.join("src/app.ts"), &app_content)?;
+
+    // Generate src/index.ts with graceful shutdown
+    let index_content = if is_express {
+        r#"import { createApp } from './app'
+import { logger } from './logger'
+
+const port = Number(process.env.PORT ?? 3000)
+const app = createApp()
+
+const server = app.listen(port, () => {
+  logger.info(`Server listening on http://localhost:${port}`)
+})
+
+function shutdown(signal: string) {
+  logger.info(`${signal} received, shutting down`)
+  server.close((err) => {
+    if (err) {
+      logger.error(err, 'Error during shutdown')
+      process.exit(1)
+    }
+    process.exit(0)
+  })
+  setTimeout(() => {
+    logger.error('Forcing shutdown after timeout')
+    process.exit(1)
+  }, 10_000).unref()
+}
+
+process.on('SIGINT', () => shutdown('SIGINT'))
+process.on('SIGTERM', () => shutdown('SIGTERM'))
+"#
+    } else {
+        r#"import { buildApp } from './app'
+
+const port = Number(process.env.PORT ?? 3000)
+const app = buildApp()
+
+async function shutdown(signal: string) {
+  app.log.info(`${signal} received, shutting down`)
+  setTimeout(() => {
+    app.log.error('Forcing shutdown after timeout')
+    process.exit(1)
+  }, 10_000).unref()
+  try {
+    await app.close()
+    process.exit(0)
+  } catch (err) {
+    app.log.error(err, 'Error during shutdown')
+    process.exit(1)
+  }
+}
+
+process.on('SIGINT', () => void shutdown('SIGINT'))
+process.on('SIGTERM', () => void shutdown('SIGTERM'))
+
+try {
+  await app.listen({ port, host: '0.0.0.0' })
+} catch (err) {
+  app.log.error(err)
+  process.exit(1)
+}
+"#
+    };
+    fs::write_file(&project_path.join("src/index.ts"), index_content)?;
+
+    // Add database setup if needed
+    database::generate_database_files(config, project_path)?;
+
+    // Generate README.md
+    let pm = &config.package_manager;
+    let readme = format!(
+        "# {}\n\nA {} API server generated with cp-cli.\n\n## Getting started\n\n```bash\n{}\n{}\n```\n\nThen check `http://localhost:3000/health`.\n\n## Scripts\n\n- `{}` - start the server with hot reload\n- `{}` - bundle for production with tsup\n- `{}` - run the production build\n- `{}` - type-check the project\n",
+        config.name,
+        config.framework,
+        pm.install_cmd(),
+        pm.dev_cmd(),
+        pm.run_cmd("dev"),
+        pm.run_cmd("build"),
+        pm.run_cmd("start"),
+        pm.run_cmd("typecheck"),
+    );
+    fs::write_file(&project_path.join("README.md"), &readme)?;
+
+    // Generate .gitignore
+    let gitignore = r#"# dependencies
+/node_modules
+
+# build
+/dist
+
+# misc
+.DS_Store
+*.pem
+
+# debug
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+
+# local env files
+.env*.local
+
+# typescript
+*.tsbuildinfo
+"#;
+    let gitignore = format!("{}{}", gitignore, database::gitignore_entries(config));
+    fs::write_file(&project_path.join(".gitignore"), &gitignore)?;
+
+    // Generate .env.local
+    let mut env_content = String::from("PORT=3000\nLOG_LEVEL=info\n");
+    for line in database::env_vars(config) {
+        env_content.push_str(&line);
+        env_content.push('\n');
+    }
+    fs::write_file(&project_path.join(".env.local"), &env_content)?;
+
+    Ok(())
+}
+
+fn express_app(use_drizzle: bool) -> String {
+    let mut content = String::from(
+        r#"import express, { type NextFunction, type Request, type Response } from 'express'
+import { pinoHttp } from 'pino-http'
+"#,
+    );
+    if use_drizzle {
+        content.push_str(
+            r#"import { eq } from 'drizzle-orm'
+import { db } from '../db'
+import { users } from '../db/schema'
+"#,
+        );
+    }
+    content.push_str(
+        r#"import { HttpError } from './errors'
+import { logger } from './logger'
+
+export function createApp() {
+  const app = express()
+
+  app.use(pinoHttp({ logger }))
+  app.use(express.json())
+
+  app.get('/health', (_req, res) => {
+    res.json({ status: 'ok' })
+  })
+
+"#,
+    );
+    if use_drizzle {
+        content.push_str(
+            r#"  app.get('/users', async (_req, res, next) => {
+    try {
+      res.json(await db.select().from(users))
+    } catch (err) {
+      next(err)
+    }
+  })
+
+  app.get('/users/:id', async (req, res, next) => {
+    try {
+      const id = Number(req.params.id)
+      if (!Number.isInteger(id)) {
+        throw new HttpError(400, 'Invalid user id')
+      }
+      const [user] = await db.select().from(users).where(eq(users.id, id))
+      if (!user) {
+        throw new HttpError(404, 'User not found')
+      }
+      res.json(user)
+    } catch (err) {
+      next(err)
+    }
+  })
+
+"#,
+        );
+    } else {
+        content.push_str(
+            r#"  app.get('/hello/:name', (req, res) => {
+    res.json({ message: `Hello, ${req.params.name}!` })
+  })
+
+"#,
+        );
+    }
+    content.push_str(
+        r#"  app.use((_req, _res, next) => {
+    next(new HttpError(404, 'Not found'))
+  })
+
+  app.use((err: unknown, req: Request, res: Response, _next: NextFunction) => {
+    const status = err instanceof HttpError ? err.status : 500
+    if (status >= 500) {
+      req.log.error(err)
+    }
+    res.status(status).json({
+      error: err instanceof HttpError ? err.message : 'Internal server error',
+    })
+  })
+
+  return app
+}
+"#,
+    );
+    content
+}
+
+fn fastify_app(use_drizzle: bool) -> String {
+    let mut content = String::from("import Fastify from 'fastify'\n");
+    if use_drizzle {
+        content.push_str(
+            r#"import { eq } from 'drizzle-orm'
+import { db } from '../db'
+import { users } from '../db/schema'
+"#,
+        );
+    }
+    content.push_str(
+        r#"import { HttpError } from './errors'
+
+export function buildApp() {
+  const app = Fastify({
+    logger: {
+      level: process.env.LOG_LEVEL ?? 'info',
+      transport:
+        process.env.NODE_ENV === 'production'
+          ? undefined
+          : { target: 'pino-pretty', options: { colorize: true } },
+    },
+  })
+
+  app.get('/health', async () => ({ status: 'ok' }))
+
+"#,
+    );
+    if use_drizzle {
+        content.push_str(
+            r#"  app.get('/users', async () => db.select().from(users))
+
+  app.get<{ Params: { id: string } }>('/users/:id', async (request) => {
+    const id = Number(request.params.id)
+    if (!Number.isInteger(id)) {
+      throw new HttpError(400, 'Invalid user id')
+    }
+    const [user] = await db.select().from(users).where(eq(users.id, id))
+    if (!user) {
+      throw new HttpError(404, 'User not found')
+    }
+    return user
+  })
+
+"#,
+        );
+    } else {
+        content.push_str(
+            r#"  app.get<{ Params: { name: string } }>('/hello/:name', async (request) => ({
+    message: `Hello, ${request.params.name}!`,
+  }))
+
+"#,
+        );
+    }
+    content.push_str(
+        r#"  app.setNotFoundHandler((_request, reply) => {
+    reply.status(404).send({ error: 'Not found' })
+  })
+
+  app.setErrorHandler((err, request, reply) => {
+    const status = err instanceof HttpError ? err.status : (err.statusCode ?? 500)
+    if (status >= 500) {
+      request.log.error(err)
+    }
+    reply.status(status).send({
+      error: status >= 500 ? 'Internal server error' : err.message,
+    })
+  })
+
+  return app
+}
+"#,
+    );
+    content
+}
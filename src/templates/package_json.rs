@@ -0,0 +1,19 @@
+use crate::utils::fs;
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::path::Path;
+
+pub fn read_package_json(project_path: &Path) -> Result<Value> {
+    let path = project_path.join("package.json");
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read file: {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse file: {}", path.display()))
+}
+
+pub fn write_package_json(project_path: &Path, package_json: &Value) -> Result<()> {
+    fs::write_file(
+        &project_path.join("package.json"),
+        &serde_json::to_string_pretty(package_json)?,
+    )
+}
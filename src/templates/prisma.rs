@@ -0,0 +1,98 @@
+use crate::config::{Auth, Framework, ProjectConfig, SqlProvider};
+use crate::templates::better_auth;
+use crate::utils::fs;
+use anyhow::Result;
+use std::path::Path;
+
+/// Writes `prisma/schema.prisma` and a client module for the framework.
+pub fn generate_prisma_files(
+    config: &ProjectConfig,
+    provider: &SqlProvider,
+    project_path: &Path,
+) -> Result<()> {
+    let datasource_provider = match provider {
+        SqlProvider::Neon | SqlProvider::Postgres => "postgresql",
+        SqlProvider::Turso | SqlProvider::Sqlite => "sqlite",
+        SqlProvider::PlanetScale => "mysql",
+    };
+    // PlanetScale doesn't enforce foreign keys, so Prisma emulates relations
+    let datasource = if matches!(provider, SqlProvider::PlanetScale) {
+        format!(
+            "  provider     = \"{}\"\n  url          = env(\"DATABASE_URL\")\n  relationMode = \"prisma\"",
+            datasource_provider
+        )
+    } else {
+        format!(
+            "  provider = \"{}\"\n  url      = env(\"DATABASE_URL\")",
+            datasource_provider
+        )
+    };
+
+    let models = match config.auth {
+        Auth::BetterAuth => better_auth::prisma_models(provider),
+        _ => r#"model User {
+  id        Int      @id @default(autoincrement())
+  email     String   @unique
+  name      String?
+  createdAt DateTime @default(now())
+}
+"#
+        .to_string(),
+    };
+
+    let schema = format!(
+        r#"// Learn more about the Prisma schema at https://pris.ly/d/prisma-schema
+
+generator client {{
+  provider = "prisma-client-js"
+}}
+
+datasource db {{
+{}
+}}
+
+{}"#,
+        datasource, models
+    );
+    fs::write_file(&project_path.join("prisma/schema.prisma"), &schema)?;
+
+    if matches!(provider, SqlProvider::Sqlite) {
+        fs::write_file(
+            &project_path.join("prisma/.gitignore"),
+            "# SQLite database\n*.db\n*.db-journal\n",
+        )?;
+    }
+
+    // Dev servers with hot reload re-evaluate modules, so keep one client on
+    // globalThis instead of opening a new connection pool on every change.
+    // API servers restart the whole process and can export a plain instance.
+    let hot_reload_client = r#"import { PrismaClient } from '@prisma/client'
+
+const globalForPrisma = globalThis as unknown as { prisma?: PrismaClient }
+
+export const prisma = globalForPrisma.prisma ?? new PrismaClient()
+
+if (process.env.NODE_ENV !== 'production') {
+  globalForPrisma.prisma = prisma
+}
+"#;
+    let process_client = r#"import { PrismaClient } from '@prisma/client'
+
+export const prisma = new PrismaClient()
+"#;
+
+    match config.framework {
+        Framework::NextJs | Framework::TanStackStart => {
+            fs::write_file(&project_path.join("lib/prisma.ts"), hot_reload_client)?;
+        }
+        Framework::SolidStart => {
+            fs::write_file(&project_path.join("src/lib/prisma.ts"), hot_reload_client)?;
+        }
+        Framework::Hono | Framework::Express | Framework::Fastify => {
+            fs::write_file(&project_path.join("src/db.ts"), process_client)?;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
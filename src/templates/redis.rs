@@ -0,0 +1,195 @@
+use crate::config::{Framework, ProjectConfig, Redis};
+use crate::templates::docker_compose;
+use crate::utils::fs;
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+pub fn add_dependencies(
+    config: &ProjectConfig,
+    deps: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    match config.redis {
+        Redis::Upstash => {
+            deps.insert("@upstash/redis".to_string(), json!("latest"));
+            deps.insert("@upstash/ratelimit".to_string(), json!("latest"));
+        }
+        Redis::Ioredis => {
+            deps.insert("ioredis".to_string(), json!("latest"));
+        }
+        Redis::None => {}
+    }
+}
+
+/// `KEY=value` lines pointing the client at the docker-compose services.
+pub fn env_vars(config: &ProjectConfig) -> Vec<String> {
+    match config.redis {
+        Redis::Upstash => vec![
+            "UPSTASH_REDIS_REST_URL=http://localhost:8079".to_string(),
+            format!(
+                "UPSTASH_REDIS_REST_TOKEN={}",
+                docker_compose::REDIS_REST_TOKEN
+            ),
+        ],
+        Redis::Ioredis => vec!["REDIS_URL=redis://localhost:6379".to_string()],
+        Redis::None => Vec::new(),
+    }
+}
+
+/// Whether Next.js middleware applies the rate limit. ioredis needs Node APIs
+/// that the edge middleware runtime lacks, so it limits in the route instead.
+pub fn limits_in_middleware(config: &ProjectConfig) -> bool {
+    matches!(config.redis, Redis::Upstash) && matches!(config.framework, Framework::NextJs)
+}
+
+/// Statements for a Next.js middleware body that reject `/api` requests over
+/// the limit. Expects `request`, `NextResponse` and `ratelimit` in scope.
+pub const MIDDLEWARE_RATE_LIMIT: &str = r#"  if (request.nextUrl.pathname.startsWith('/api')) {
+    const ip = request.headers.get('x-forwarded-for')?.split(',')[0] ?? '127.0.0.1'
+    const { success, limit, remaining, reset } = await ratelimit.limit(ip)
+    if (!success) {
+      return NextResponse.json(
+        { error: 'Too many requests' },
+        {
+          status: 429,
+          headers: {
+            'X-RateLimit-Limit': String(limit),
+            'X-RateLimit-Remaining': String(remaining),
+            'X-RateLimit-Reset': String(reset),
+          },
+        },
+      )
+    }
+  }
+"#;
+
+/// Writes the client, a cache helper, the rate limiter and an example that
+/// uses it.
+pub fn generate_redis_files(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    let (client, ratelimit) = match config.redis {
+        Redis::Upstash => (
+            r#"import { Redis } from '@upstash/redis'
+
+// Reads UPSTASH_REDIS_REST_URL and UPSTASH_REDIS_REST_TOKEN
+export const redis = Redis.fromEnv()
+
+/** Returns the cached value for `key`, computing and storing it on a miss. */
+export async function cached<T>(key: string, ttlSeconds: number, load: () => Promise<T>) {
+  const hit = await redis.get<T>(key)
+  if (hit !== null) {
+    return hit
+  }
+  const value = await load()
+  await redis.set(key, value, { ex: ttlSeconds })
+  return value
+}
+"#,
+            r#"import { Ratelimit } from '@upstash/ratelimit'
+import { redis } from './redis'
+
+// 10 requests per 10 seconds for each identifier
+export const ratelimit = new Ratelimit({
+  redis,
+  limiter: Ratelimit.slidingWindow(10, '10 s'),
+  prefix: 'ratelimit',
+})
+"#,
+        ),
+        Redis::Ioredis => (
+            r#"import Redis from 'ioredis'
+
+// Reuse one connection across hot reloads in development
+const globalForRedis = globalThis as unknown as { redis?: Redis }
+
+export const redis = globalForRedis.redis ?? new Redis(process.env.REDIS_URL!)
+
+if (process.env.NODE_ENV !== 'production') {
+  globalForRedis.redis = redis
+}
+
+/** Returns the cached value for `key`, computing and storing it on a miss. */
+export async function cached<T>(key: string, ttlSeconds: number, load: () => Promise<T>) {
+  const hit = await redis.get(key)
+  if (hit !== null) {
+    return JSON.parse(hit) as T
+  }
+  const value = await load()
+  await redis.set(key, JSON.stringify(value), 'EX', ttlSeconds)
+  return value
+}
+"#,
+            r#"import { redis } from './redis'
+
+const LIMIT = 10
+const WINDOW_SECONDS = 10
+
+// Fixed-window limiter: one counter per identifier and window
+export const ratelimit = {
+  async limit(identifier: string) {
+    const window = Math.floor(Date.now() / 1000 / WINDOW_SECONDS)
+    const key = `ratelimit:${identifier}:${window}`
+    const count = await redis.incr(key)
+    if (count === 1) {
+      await redis.expire(key, WINDOW_SECONDS)
+    }
+    return {
+      success: count <= LIMIT,
+      limit: LIMIT,
+      remaining: Math.max(0, LIMIT - count),
+      reset: (window + 1) * WINDOW_SECONDS * 1000,
+    }
+  },
+}
+"#,
+        ),
+        Redis::None => return Ok(()),
+    };
+    fs::write_file(&project_path.join("lib/redis.ts"), client)?;
+    fs::write_file(&project_path.join("lib/ratelimit.ts"), ratelimit)?;
+
+    match config.framework {
+        Framework::NextJs => {
+            let route = if limits_in_middleware(config) {
+                r#"// Rate limited by middleware.ts
+export function GET() {
+  return Response.json({ message: 'Hello from a rate-limited route' })
+}
+"#
+            } else {
+                r#"import { ratelimit } from '@/lib/ratelimit'
+
+export async function GET(request: Request) {
+  const ip = request.headers.get('x-forwarded-for')?.split(',')[0] ?? '127.0.0.1'
+  const { success } = await ratelimit.limit(ip)
+  if (!success) {
+    return Response.json({ error: 'Too many requests' }, { status: 429 })
+  }
+
+  return Response.json({ message: 'Hello from a rate-limited route' })
+}
+"#
+            };
+            fs::write_file(&project_path.join("app/api/hello/route.ts"), route)?;
+        }
+        Framework::TanStackStart => {
+            let server_fn = r#"import { createServerFn } from '@tanstack/start'
+import { getRequestIP } from 'vinxi/http'
+import { ratelimit } from './ratelimit'
+
+export const getGreeting = createServerFn({ method: 'GET' }).handler(async () => {
+  const ip = getRequestIP({ xForwardedFor: true }) ?? '127.0.0.1'
+  const { success } = await ratelimit.limit(ip)
+  if (!success) {
+    throw new Error('Too many requests')
+  }
+
+  return { message: 'Hello from a rate-limited server function' }
+})
+"#;
+            fs::write_file(&project_path.join("lib/greeting.ts"), server_fn)?;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
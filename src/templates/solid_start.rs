@@ -0,0 +1,322 @@
+use crate::config::{Database, Orm, ProjectConfig};
+use crate::templates::{database, package_json};
+use crate::utils::fs;
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+pub fn generate_solid_start_project(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    // Generate package.json
+    let mut deps = serde_json::Map::new();
+    deps.insert("@solidjs/start".to_string(), json!("latest"));
+    deps.insert("@solidjs/router".to_string(), json!("latest"));
+    deps.insert("@solidjs/meta".to_string(), json!("latest"));
+    deps.insert("solid-js".to_string(), json!("latest"));
+    deps.insert("vinxi".to_string(), json!("latest"));
+
+    if config.use_tailwind {
+        deps.insert("tailwindcss".to_string(), json!("^3"));
+        deps.insert("postcss".to_string(), json!("latest"));
+        deps.insert("autoprefixer".to_string(), json!("latest"));
+    }
+
+    database::add_dependencies(config, &mut deps);
+
+    let mut package_json = json!({
+        "name": config.name,
+        "version": "0.1.0",
+        "private": true,
+        "type": "module",
+        "scripts": {
+            "dev": "vinxi dev",
+            "build": "vinxi build",
+            "start": "vinxi start"
+        },
+        "dependencies": deps,
+        "devDependencies": {
+            "typescript": "latest",
+            "@types/node": "latest"
+        }
+    });
+
+    database::add_scripts(config, &mut package_json);
+
+    package_json::write_package_json(project_path, &package_json)?;
+
+    // Generate tsconfig.json
+    let tsconfig = json!({
+        "compilerOptions": {
+            "target": "ESNext",
+            "module": "ESNext",
+            "moduleResolution": "bundler",
+            "allowSyntheticDefaultImports": true,
+            "esModuleInterop": true,
+            "jsx": "preserve",
+            "jsxImportSource": "solid-js",
+            "allowJs": true,
+            "strict": true,
+            "noEmit": true,
+            "skipLibCheck": true,
+            "isolatedModules": true,
+            "types": ["vinxi/types/client"],
+            "paths": {
+                "~/*": ["./src/*"]
+            }
+        },
+        "exclude": ["node_modules"]
+    });
+
+    fs::write_file(
+        &project_path.join("tsconfig.json"),
+        &serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+
+    // Generate app.config.ts
+    let app_config = r#"import { defineConfig } from '@solidjs/start/config'
+
+export default defineConfig({})
+"#;
+    fs::write_file(&project_path.join("app.config.ts"), app_config)?;
+
+    // Create src directory structure
+    fs::create_directory(&project_path.join("src/routes"))?;
+
+    // Generate src/app.tsx
+    let app_content = r#"import { MetaProvider, Title } from '@solidjs/meta'
+import { Router } from '@solidjs/router'
+import { FileRoutes } from '@solidjs/start/router'
+import { Suspense } from 'solid-js'
+import './app.css'
+
+export default function App() {
+  return (
+    <Router
+      root={(props) => (
+        <MetaProvider>
+          <Title>{{PROJECT_NAME}}</Title>
+          <Suspense>{props.children}</Suspense>
+        </MetaProvider>
+      )}
+    >
+      <FileRoutes />
+    </Router>
+  )
+}
+"#;
+    let app_content = app_content.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(&project_path.join("src/app.tsx"), &app_content)?;
+
+    // Generate entry points
+    let entry_client = r#"// @refresh reload
+import { mount, StartClient } from '@solidjs/start/client'
+
+mount(() => <StartClient />, document.getElementById('app')!)
+"#;
+    fs::write_file(&project_path.join("src/entry-client.tsx"), entry_client)?;
+
+    let entry_server = r#"// @refresh reload
+import { createHandler, StartServer } from '@solidjs/start/server'
+
+export default createHandler(() => (
+  <StartServer
+    document={({ assets, children, scripts }) => (
+      <html lang="en">
+        <head>
+          <meta charset="utf-8" />
+          <meta name="viewport" content="width=device-width, initial-scale=1" />
+          {assets}
+        </head>
+        <body>
+          <div id="app">{children}</div>
+          {scripts}
+        </body>
+      </html>
+    )}
+  />
+))
+"#;
+    fs::write_file(&project_path.join("src/entry-server.tsx"), entry_server)?;
+
+    // Generate src/routes/index.tsx
+    let index_route = match config.database {
+        Database::Sql(Orm::Drizzle, _) => {
+            r#"import { For } from 'solid-js'
+import { createAsync, query } from '@solidjs/router'
+import { db } from '../../db'
+import { users } from '../../db/schema'
+
+const getUsers = query(async () => {
+  'use server'
+  return db.select().from(users)
+}, 'users')
+
+export const route = {
+  preload: () => getUsers(),
+}
+
+export default function Home() {
+  const rows = createAsync(() => getUsers())
+
+  return (
+    <main>
+      <h1>Welcome to {{PROJECT_NAME}}</h1>
+      <p>Get started by editing src/routes/index.tsx</p>
+      <ul>
+        <For each={rows() ?? []}>{(user) => <li>{user.name}</li>}</For>
+      </ul>
+    </main>
+  )
+}
+"#
+        }
+        Database::Convex
+        | Database::Sql(..)
+        | Database::MongoDB
+        | Database::Supabase
+        | Database::Firebase
+        | Database::None => {
+            r#"export default function Home() {
+  return (
+    <main>
+      <h1>Welcome to {{PROJECT_NAME}}</h1>
+      <p>Get started by editing src/routes/index.tsx</p>
+    </main>
+  )
+}
+"#
+        }
+    };
+    let index_route = index_route.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(&project_path.join("src/routes/index.tsx"), &index_route)?;
+
+    // Generate src/app.css
+    let app_css = if config.use_tailwind {
+        r#"@tailwind base;
+@tailwind components;
+@tailwind utilities;
+"#
+    } else {
+        r#"* {
+  box-sizing: border-box;
+  padding: 0;
+  margin: 0;
+}
+"#
+    };
+    fs::write_file(&project_path.join("src/app.css"), app_css)?;
+
+    // Add Tailwind config if needed
+    if config.use_tailwind {
+        let tailwind_config = r#"/** @type {import('tailwindcss').Config} */
+export default {
+  content: ['./src/**/*.{js,ts,jsx,tsx}'],
+  theme: {
+    extend: {},
+  },
+  plugins: [],
+}
+"#;
+        fs::write_file(&project_path.join("tailwind.config.js"), tailwind_config)?;
+
+        let postcss_config = r#"export default {
+  plugins: {
+    tailwindcss: {},
+    autoprefixer: {},
+  },
+}
+"#;
+        fs::write_file(&project_path.join("postcss.config.js"), postcss_config)?;
+    }
+
+    // Add database setup if needed
+    database::generate_database_files(config, project_path)?;
+
+    match config.database {
+        Database::Convex => {
+            // Convex's client exposes subscriptions; wrap them in Solid signals
+            let convex_client = r#"// Usage: const tasks = createQuery(api.tasks.list, {})
+import { ConvexClient } from 'convex/browser'
+import type {
+  FunctionArgs,
+  FunctionReference,
+  FunctionReturnType,
+} from 'convex/server'
+import { createSignal, onCleanup, onMount } from 'solid-js'
+
+let client: ConvexClient | undefined
+
+export function getConvexClient() {
+  client ??= new ConvexClient(import.meta.env.VITE_CONVEX_URL as string)
+  return client
+}
+
+export function createQuery<Query extends FunctionReference<'query'>>(
+  query: Query,
+  args: FunctionArgs<Query>,
+) {
+  const [data, setData] = createSignal<FunctionReturnType<Query>>()
+
+  onMount(() => {
+    const unsubscribe = getConvexClient().onUpdate(query, args, (result) =>
+      setData(() => result),
+    )
+    onCleanup(unsubscribe)
+  })
+
+  return data
+}
+
+export function createMutation<Mutation extends FunctionReference<'mutation'>>(
+  mutation: Mutation,
+) {
+  return (args: FunctionArgs<Mutation>) => getConvexClient().mutation(mutation, args)
+}
+"#;
+            fs::write_file(&project_path.join("src/lib/convex.ts"), convex_client)?;
+        }
+        Database::Sql(..)
+        | Database::MongoDB
+        | Database::Supabase
+        | Database::Firebase
+        | Database::None => {}
+    }
+
+    // Generate .gitignore
+    let gitignore = r#"# dependencies
+/node_modules
+
+# build
+/dist
+/.output
+/.vinxi
+
+# misc
+.DS_Store
+*.pem
+
+# debug
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+
+# local env files
+.env*.local
+
+# typescript
+*.tsbuildinfo
+"#;
+    let gitignore = format!("{}{}", gitignore, database::gitignore_entries(config));
+    fs::write_file(&project_path.join(".gitignore"), &gitignore)?;
+
+    // Generate .env.local if needed
+    let mut env_content = String::new();
+    for line in database::env_vars(config) {
+        env_content.push_str(&line);
+        env_content.push('\n');
+    }
+    if !env_content.is_empty() {
+        fs::write_file(&project_path.join(".env.local"), &env_content)?;
+    }
+
+    Ok(())
+}
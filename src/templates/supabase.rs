@@ -0,0 +1,237 @@
+use crate::config::{Framework, ProjectConfig};
+use crate::utils::fs;
+use anyhow::Result;
+use std::path::Path;
+
+/// Writes the Supabase CLI project (`supabase/`) and the framework's
+/// `@supabase/ssr` client helpers.
+pub fn generate_supabase_files(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    // Generate supabase/config.toml for the local stack (`supabase start`)
+    let config_toml = r#"# Configuration for the local Supabase stack. See
+# https://supabase.com/docs/guides/local-development/cli/config
+project_id = "{{PROJECT_NAME}}"
+
+[api]
+enabled = true
+port = 54321
+schemas = ["public", "graphql_public"]
+extra_search_path = ["public", "extensions"]
+max_rows = 1000
+
+[db]
+port = 54322
+major_version = 15
+
+[db.seed]
+enabled = true
+sql_paths = ["./seed.sql"]
+
+[studio]
+enabled = true
+port = 54323
+
+[inbucket]
+enabled = true
+port = 54324
+
+[auth]
+enabled = true
+site_url = "http://localhost:3000"
+additional_redirect_urls = ["http://localhost:3000/**"]
+jwt_expiry = 3600
+enable_signup = true
+
+[auth.email]
+enable_signup = true
+enable_confirmations = false
+"#;
+    let config_toml = config_toml.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(&project_path.join("supabase/config.toml"), &config_toml)?;
+
+    let migration = r#"-- Example table; each row belongs to the user who created it
+create table public.todos (
+  id bigint generated by default as identity primary key,
+  user_id uuid not null default auth.uid() references auth.users (id) on delete cascade,
+  title text not null,
+  completed boolean not null default false,
+  created_at timestamptz not null default now()
+);
+
+alter table public.todos enable row level security;
+
+create policy "Users can read their own todos"
+  on public.todos for select
+  using ((select auth.uid()) = user_id);
+
+create policy "Users can create their own todos"
+  on public.todos for insert
+  with check ((select auth.uid()) = user_id);
+
+create policy "Users can update their own todos"
+  on public.todos for update
+  using ((select auth.uid()) = user_id);
+
+create policy "Users can delete their own todos"
+  on public.todos for delete
+  using ((select auth.uid()) = user_id);
+"#;
+    fs::write_file(
+        &project_path.join("supabase/migrations/20240101000000_init.sql"),
+        migration,
+    )?;
+    fs::write_file(
+        &project_path.join("supabase/seed.sql"),
+        "-- Rows inserted after `supabase db reset` applies the migrations\n",
+    )?;
+
+    fs::write_file(
+        &project_path.join("supabase/.gitignore"),
+        "# Supabase\n.branches\n.temp\n",
+    )?;
+
+    // Generate client helpers
+    match config.framework {
+        Framework::NextJs => generate_nextjs_clients(project_path),
+        Framework::TanStackStart => generate_tanstack_clients(project_path),
+        _ => Ok(()),
+    }
+}
+
+/// Root `middleware.ts` calling `updateSession` is written by the Next.js
+/// template alongside any other middleware.
+fn generate_nextjs_clients(project_path: &Path) -> Result<()> {
+    let client = r#"import { createBrowserClient } from '@supabase/ssr'
+
+export function createClient() {
+  return createBrowserClient(
+    process.env.NEXT_PUBLIC_SUPABASE_URL!,
+    process.env.NEXT_PUBLIC_SUPABASE_ANON_KEY!,
+  )
+}
+"#;
+    fs::write_file(&project_path.join("lib/supabase/client.ts"), client)?;
+
+    let server = r#"import { createServerClient } from '@supabase/ssr'
+import { cookies } from 'next/headers'
+
+export async function createClient() {
+  const cookieStore = await cookies()
+
+  return createServerClient(
+    process.env.NEXT_PUBLIC_SUPABASE_URL!,
+    process.env.NEXT_PUBLIC_SUPABASE_ANON_KEY!,
+    {
+      cookies: {
+        getAll() {
+          return cookieStore.getAll()
+        },
+        setAll(cookiesToSet) {
+          try {
+            cookiesToSet.forEach(({ name, value, options }) =>
+              cookieStore.set(name, value, options),
+            )
+          } catch {
+            // Server Components can't set cookies; the middleware refreshes
+            // the session instead
+          }
+        },
+      },
+    },
+  )
+}
+"#;
+    fs::write_file(&project_path.join("lib/supabase/server.ts"), server)?;
+
+    let session = r#"import { createServerClient } from '@supabase/ssr'
+import { NextResponse, type NextRequest } from 'next/server'
+
+export async function updateSession(request: NextRequest) {
+  let response = NextResponse.next({ request })
+
+  const supabase = createServerClient(
+    process.env.NEXT_PUBLIC_SUPABASE_URL!,
+    process.env.NEXT_PUBLIC_SUPABASE_ANON_KEY!,
+    {
+      cookies: {
+        getAll() {
+          return request.cookies.getAll()
+        },
+        setAll(cookiesToSet) {
+          cookiesToSet.forEach(({ name, value }) => request.cookies.set(name, value))
+          response = NextResponse.next({ request })
+          cookiesToSet.forEach(({ name, value, options }) =>
+            response.cookies.set(name, value, options),
+          )
+        },
+      },
+    },
+  )
+
+  // Refreshes the auth token; keep this directly after creating the client
+  await supabase.auth.getUser()
+
+  return response
+}
+"#;
+    fs::write_file(&project_path.join("lib/supabase/middleware.ts"), session)?;
+
+    Ok(())
+}
+
+fn generate_tanstack_clients(project_path: &Path) -> Result<()> {
+    let client = r#"import { createBrowserClient } from '@supabase/ssr'
+
+export function createClient() {
+  return createBrowserClient(
+    import.meta.env.VITE_SUPABASE_URL,
+    import.meta.env.VITE_SUPABASE_ANON_KEY,
+  )
+}
+"#;
+    fs::write_file(&project_path.join("lib/supabase/client.ts"), client)?;
+
+    let server = r#"import { createServerClient } from '@supabase/ssr'
+import { parseCookies, setCookie } from 'vinxi/http'
+
+export function getSupabaseServerClient() {
+  return createServerClient(
+    process.env.VITE_SUPABASE_URL!,
+    process.env.VITE_SUPABASE_ANON_KEY!,
+    {
+      cookies: {
+        getAll() {
+          return Object.entries(parseCookies()).map(([name, value]) => ({
+            name,
+            value,
+          }))
+        },
+        setAll(cookiesToSet) {
+          cookiesToSet.forEach(({ name, value, options }) =>
+            setCookie(name, value, options),
+          )
+        },
+      },
+    },
+  )
+}
+"#;
+    fs::write_file(&project_path.join("lib/supabase/server.ts"), server)?;
+
+    let user = r#"import { createServerFn } from '@tanstack/start'
+import { getSupabaseServerClient } from './server'
+
+export const fetchUser = createServerFn({ method: 'GET' }).handler(async () => {
+  const supabase = getSupabaseServerClient()
+  const { data, error } = await supabase.auth.getUser()
+
+  if (error || !data.user) {
+    return null
+  }
+
+  return { id: data.user.id, email: data.user.email }
+})
+"#;
+    fs::write_file(&project_path.join("lib/supabase/user.ts"), user)?;
+
+    Ok(())
+}
@@ -1,10 +1,14 @@
-use crate::config::ProjectConfig;
+use crate::config::{Auth, Database, ProjectConfig, Redis};
+use crate::templates::{better_auth, database, package_json, redis};
 use crate::utils::fs;
 use anyhow::Result;
 use serde_json::json;
 use std::path::Path;
 
 pub fn generate_tanstack_project(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    let use_clerk = matches!(config.auth, Auth::Clerk);
+    let use_better_auth = matches!(config.auth, Auth::BetterAuth);
+
     // Generate package.json
     let mut deps = serde_json::Map::new();
     deps.insert("@tanstack/start".to_string(), json!("latest"));
@@ -19,23 +23,17 @@ pub fn generate_tanstack_project(config: &ProjectConfig, project_path: &Path) ->
         deps.insert("autoprefixer".to_string(), json!("latest"));
     }
 
-    if config.use_clerk {
+    if use_clerk {
         deps.insert("@clerk/clerk-react".to_string(), json!("latest"));
     }
-
-    match config.database {
-        crate::config::Database::Convex => {
-            deps.insert("convex".to_string(), json!("latest"));
-        }
-        crate::config::Database::NeonDrizzle => {
-            deps.insert("drizzle-orm".to_string(), json!("latest"));
-            deps.insert("@neondatabase/serverless".to_string(), json!("latest"));
-            deps.insert("drizzle-kit".to_string(), json!("latest"));
-        }
-        crate::config::Database::None => {}
+    if use_better_auth {
+        better_auth::add_dependencies(config, &mut deps);
     }
 
-    let package_json = json!({
+    database::add_dependencies(config, &mut deps);
+    redis::add_dependencies(config, &mut deps);
+
+    let mut package_json = json!({
         "name": config.name,
         "version": "0.1.0",
         "private": true,
@@ -49,14 +47,14 @@ pub fn generate_tanstack_project(config: &ProjectConfig, project_path: &Path) ->
             "typescript": "latest",
             "@types/node": "latest",
             "@types/react": "latest",
-            "@types/react-dom": "latest"
+            "@types/react-dom": "latest",
+            "vite-tsconfig-paths": "latest"
         }
     });
 
-    fs::write_file(
-        &project_path.join("package.json"),
-        &serde_json::to_string_pretty(&package_json)?,
-    )?;
+    database::add_scripts(config, &mut package_json);
+
+    package_json::write_package_json(project_path, &package_json)?;
 
     // Generate tsconfig.json
     let tsconfig = json!({
@@ -92,9 +90,9 @@ pub fn generate_tanstack_project(config: &ProjectConfig, project_path: &Path) ->
 import { createRootRoute, createRoute } from '@tanstack/react-router'
 import { StrictMode } from 'react'
 import { createRoot } from 'react-dom/client'
-import './app.css'
+{{IMPORTS}}{{PAGE_IMPORTS}}import './app.css'
 
-const rootRoute = createRootRoute({
+{{SETUP}}const rootRoute = createRootRoute({
   component: () => {
     return (
       <>
@@ -106,18 +104,18 @@ const rootRoute = createRootRoute({
 
 const indexRoute = createRoute({
   getParentRoute: () => rootRoute,
-  path: '/',
-  component: () => {
+  path: '/',{{INDEX_LOADER}}
+  component: () => {{{INDEX_SETUP}}
     return (
       <div>
         <h1>Welcome to {{PROJECT_NAME}}</h1>
-        <p>Get started by editing app.tsx</p>
+        <p>Get started by editing app.tsx</p>{{INDEX_CONTENT}}
       </div>
     )
   },
 })
-
-const routeTree = rootRoute.addChildren([indexRoute])
+{{ROUTES}}
+const routeTree = rootRoute.addChildren([{{CHILDREN}}])
 
 const router = createRouter({ routeTree })
 
@@ -134,11 +132,67 @@ function App() {
 const rootElement = document.getElementById('root')!
 createRoot(rootElement).render(
   <StrictMode>
-    <App />
+{{APP}}
   </StrictMode>,
 )
 "#;
-    let app_content = app_content.replace("{{PROJECT_NAME}}", &config.name);
+    let use_convex = matches!(config.database, Database::Convex);
+    let convex_setup =
+        "const convex = new ConvexReactClient(import.meta.env.VITE_CONVEX_URL as string)\n\n";
+    let (imports, setup, app) = if use_convex && use_clerk {
+        // Convex authenticates with the Clerk session's token
+        (
+            "import { ClerkProvider, useAuth } from '@clerk/clerk-react'\nimport { ConvexReactClient } from 'convex/react'\nimport { ConvexProviderWithClerk } from 'convex/react-clerk'\n",
+            convex_setup,
+            "    <ClerkProvider publishableKey={import.meta.env.VITE_CLERK_PUBLISHABLE_KEY}>\n      <ConvexProviderWithClerk client={convex} useAuth={useAuth}>\n        <App />\n      </ConvexProviderWithClerk>\n    </ClerkProvider>",
+        )
+    } else if use_convex {
+        (
+            "import { ConvexProvider, ConvexReactClient } from 'convex/react'\n",
+            convex_setup,
+            "    <ConvexProvider client={convex}>\n      <App />\n    </ConvexProvider>",
+        )
+    } else {
+        ("", "", "    <App />")
+    };
+    // The index route loads the rate-limited greeting from lib/greeting.ts
+    let (greeting_imports, index_loader, index_setup, index_content) = if matches!(
+        config.redis,
+        Redis::None
+    ) {
+        ("", "", "", "")
+    } else {
+        (
+            "import { useLoaderData } from '@tanstack/react-router'\nimport { getGreeting } from './lib/greeting'\n",
+            "\n  loader: () => getGreeting(),",
+            "\n    const greeting = useLoaderData({ from: '/' })",
+            "\n        <p>{greeting.message}</p>",
+        )
+    };
+    // Better Auth's sign-in and sign-up pages are components in components/
+    let (page_imports, routes, children) = if use_better_auth {
+        (
+            "import SignIn from './components/sign-in'\nimport SignUp from './components/sign-up'\n",
+            "\nconst signInRoute = createRoute({\n  getParentRoute: () => rootRoute,\n  path: '/sign-in',\n  component: SignIn,\n})\n\nconst signUpRoute = createRoute({\n  getParentRoute: () => rootRoute,\n  path: '/sign-up',\n  component: SignUp,\n})\n",
+            "indexRoute, signInRoute, signUpRoute",
+        )
+    } else {
+        ("", "", "indexRoute")
+    };
+    let app_content = app_content
+        .replace("{{PROJECT_NAME}}", &config.name)
+        .replace("{{IMPORTS}}", imports)
+        .replace(
+            "{{PAGE_IMPORTS}}",
+            &format!("{}{}", greeting_imports, page_imports),
+        )
+        .replace("{{SETUP}}", setup)
+        .replace("{{INDEX_LOADER}}", index_loader)
+        .replace("{{INDEX_SETUP}}", index_setup)
+        .replace("{{INDEX_CONTENT}}", index_content)
+        .replace("{{ROUTES}}", routes)
+        .replace("{{CHILDREN}}", children)
+        .replace("{{APP}}", app);
     fs::write_file(&project_path.join("app.tsx"), &app_content)?;
 
     // Generate app.css
@@ -174,13 +228,15 @@ createRoot(rootElement).render(
     let index_html = index_html.replace("{{PROJECT_NAME}}", &config.name);
     fs::write_file(&project_path.join("index.html"), &index_html)?;
 
-    // Generate app.config.ts (TanStack Start uses app.config.ts)
+    // Generate app.config.ts (TanStack Start uses app.config.ts); the
+    // tsconfig-paths plugin resolves the `@/` imports
     let app_config = r#"import { defineConfig } from '@tanstack/start/config'
 import { vitePlugin } from '@tanstack/start/vite'
+import tsConfigPaths from 'vite-tsconfig-paths'
 
 export default defineConfig({
   vite: {
-    plugins: [vitePlugin()],
+    plugins: [vitePlugin(), tsConfigPaths()],
   },
 })
 "#;
@@ -212,46 +268,11 @@ module.exports = {
     }
 
     // Add database setup if needed
-    match config.database {
-        crate::config::Database::Convex => {
-            fs::create_directory(&project_path.join("convex"))?;
-            let schema_content = r#"import { defineSchema, defineTable } from "convex/server";
-import { v } from "convex/values";
-
-export default defineSchema({
-  // Define your tables here
-  // example: exampleTable: defineTable({ name: v.string() }),
-});
-"#;
-            fs::write_file(&project_path.join("convex/schema.ts"), schema_content)?;
-        }
-        crate::config::Database::NeonDrizzle => {
-            fs::create_directory(&project_path.join("db"))?;
-            let schema_content = r#"import { pgTable, serial, text, timestamp } from 'drizzle-orm/pg-core';
-
-export const users = pgTable('users', {
-  id: serial('id').primaryKey(),
-  name: text('name').notNull(),
-  email: text('email').notNull(),
-  createdAt: timestamp('created_at').defaultNow(),
-});
-"#;
-            fs::write_file(&project_path.join("db/schema.ts"), schema_content)?;
+    database::generate_database_files(config, project_path)?;
+    redis::generate_redis_files(config, project_path)?;
 
-            let drizzle_config = r#"import type { Config } from 'drizzle-kit';
-
-export default {
-  schema: './db/schema.ts',
-  out: './drizzle',
-  driver: 'pg',
-  dbCredentials: {
-    connectionString: process.env.DATABASE_URL!,
-  },
-} satisfies Config;
-"#;
-            fs::write_file(&project_path.join("drizzle.config.ts"), drizzle_config)?;
-        }
-        crate::config::Database::None => {}
+    if use_better_auth {
+        better_auth::generate_better_auth_files(config, project_path)?;
     }
 
     // Generate .gitignore
@@ -277,17 +298,25 @@ yarn-error.log*
 # typescript
 *.tsbuildinfo
 "#;
-    fs::write_file(&project_path.join(".gitignore"), gitignore)?;
+    let gitignore = format!("{}{}", gitignore, database::gitignore_entries(config));
+    fs::write_file(&project_path.join(".gitignore"), &gitignore)?;
 
     // Generate .env.local if needed
-    if config.use_clerk || matches!(config.database, crate::config::Database::NeonDrizzle) {
+    let mut env = match config.auth {
+        Auth::Clerk => vec![
+            "VITE_CLERK_PUBLISHABLE_KEY=".to_string(),
+            "CLERK_SECRET_KEY=".to_string(),
+        ],
+        Auth::BetterAuth => better_auth::env_vars()?,
+        Auth::NextAuth | Auth::None => Vec::new(),
+    };
+    env.extend(database::env_vars(config));
+    env.extend(redis::env_vars(config));
+    if !env.is_empty() {
         let mut env_content = String::new();
-        if config.use_clerk {
-            env_content.push_str("VITE_CLERK_PUBLISHABLE_KEY=\n");
-            env_content.push_str("CLERK_SECRET_KEY=\n");
-        }
-        if matches!(config.database, crate::config::Database::NeonDrizzle) {
-            env_content.push_str("DATABASE_URL=\n");
+        for line in &env {
+            env_content.push_str(line);
+            env_content.push('\n');
         }
         fs::write_file(&project_path.join(".env.local"), &env_content)?;
     }
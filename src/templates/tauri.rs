@@ -0,0 +1,249 @@
+use crate::config::ProjectConfig;
+use crate::templates::{package_json, vite_react};
+use crate::utils::{fs, name};
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+/// 32x32 RGBA placeholder icon; `tauri icon` regenerates the full set.
+const ICON_PNG: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x08, 0x06, 0x00, 0x00, 0x00, 0x73, 0x7a, 0x7a,
+    0xf4, 0x00, 0x00, 0x00, 0x30, 0x49, 0x44, 0x41, 0x54, 0x78, 0xda, 0xed, 0xce, 0x21, 0x01, 0x00,
+    0x00, 0x08, 0x03, 0x30, 0xc2, 0xd0, 0x3f, 0x07, 0x1d, 0x08, 0x03, 0x31, 0x6e, 0x26, 0xe6, 0x57,
+    0x3d, 0x7b, 0x49, 0x25, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+    0x20, 0x20, 0x90, 0x0e, 0x3c, 0x85, 0xbf, 0x18, 0xa6, 0xcb, 0x9a, 0x96, 0xd1, 0x00, 0x00, 0x00,
+    0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+pub fn generate_tauri_project(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    // The frontend is a regular Vite + React app
+    vite_react::generate_vite_react_project(config, project_path)?;
+
+    // Add the Tauri packages and scripts to package.json
+    let mut package_json = package_json::read_package_json(project_path)?;
+    package_json["dependencies"]["@tauri-apps/api"] = json!("^2");
+    package_json["devDependencies"]["@tauri-apps/cli"] = json!("^2");
+    package_json["scripts"]["tauri"] = json!("tauri");
+    package_json::write_package_json(project_path, &package_json)?;
+
+    // Generate vite.config.ts (Tauri expects a fixed port and keeps the
+    // terminal output of the Rust build visible)
+    let vite_config = r#"import { defineConfig } from 'vite'
+import react from '@vitejs/plugin-react'
+import path from 'node:path'
+
+const host = process.env.TAURI_DEV_HOST
+
+export default defineConfig({
+  plugins: [react()],
+  resolve: {
+    alias: {
+      '@': path.resolve(__dirname, './src'),
+    },
+  },
+  clearScreen: false,
+  server: {
+    port: 1420,
+    strictPort: true,
+    host: host || false,
+    hmr: host ? { protocol: 'ws', host, port: 1421 } : undefined,
+    watch: {
+      ignored: ['**/src-tauri/**'],
+    },
+  },
+})
+"#;
+    fs::write_file(&project_path.join("vite.config.ts"), vite_config)?;
+
+    // Generate src/App.tsx calling the example command
+    let app_content = r#"import { useState } from 'react'
+import { invoke } from '@tauri-apps/api/core'
+
+export default function App() {
+  const [name, setName] = useState('')
+  const [greeting, setGreeting] = useState('')
+
+  async function greet() {
+    setGreeting(await invoke<string>('greet', { name }))
+  }
+
+  return (
+    <main>
+      <h1>Welcome to {{PROJECT_NAME}}</h1>
+      <form
+        onSubmit={(e) => {
+          e.preventDefault()
+          void greet()
+        }}
+      >
+        <input
+          value={name}
+          onChange={(e) => setName(e.currentTarget.value)}
+          placeholder="Enter a name..."
+        />
+        <button type="submit">Greet</button>
+      </form>
+      <p>{greeting}</p>
+    </main>
+  )
+}
+"#;
+    let app_content = app_content.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(&project_path.join("src/App.tsx"), &app_content)?;
+
+    // Generate the Rust backend in src-tauri/
+    let tauri_path = project_path.join("src-tauri");
+    let crate_name = name::slug(&config.name);
+
+    let cargo_toml = format!(
+        r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+[build-dependencies]
+tauri-build = {{ version = "2", features = [] }}
+
+[dependencies]
+tauri = {{ version = "2", features = [] }}
+serde = {{ version = "1", features = ["derive"] }}
+serde_json = "1"
+"#,
+        crate_name
+    );
+    fs::write_file(&tauri_path.join("Cargo.toml"), &cargo_toml)?;
+
+    let build_rs = r#"fn main() {
+    tauri_build::build()
+}
+"#;
+    fs::write_file(&tauri_path.join("build.rs"), build_rs)?;
+
+    let main_rs = r#"// Prevents an additional console window on Windows in release builds
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+/// Example command, called from the frontend with `invoke('greet', { name })`.
+#[tauri::command]
+fn greet(name: &str) -> String {
+    format!("Hello, {}! You've been greeted from Rust!", name)
+}
+
+fn main() {
+    tauri::Builder::default()
+        .invoke_handler(tauri::generate_handler![greet])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
+"#;
+    fs::write_file(&tauri_path.join("src/main.rs"), main_rs)?;
+
+    let pm = &config.package_manager;
+    let tauri_conf = json!({
+        "$schema": "https://schema.tauri.app/config/2",
+        "productName": config.name,
+        "version": "0.1.0",
+        // Bundle identifiers may not contain `_`
+        "identifier": format!("com.{}.app", crate_name.replace(['-', '_'], "")),
+        "build": {
+            "beforeDevCommand": pm.run_cmd("dev"),
+            "devUrl": "http://localhost:1420",
+            "beforeBuildCommand": pm.run_cmd("build"),
+            "frontendDist": "../dist"
+        },
+        "app": {
+            "windows": [
+                {
+                    "title": config.name,
+                    "width": 800,
+                    "height": 600
+                }
+            ],
+            "security": {
+                "csp": null
+            }
+        },
+        "bundle": {
+            "active": true,
+            "targets": "all",
+            "icon": ["icons/icon.png", "icons/icon.ico"]
+        }
+    });
+    fs::write_file(
+        &tauri_path.join("tauri.conf.json"),
+        &serde_json::to_string_pretty(&tauri_conf)?,
+    )?;
+
+    let capabilities = json!({
+        "$schema": "../gen/schemas/desktop-schema.json",
+        "identifier": "default",
+        "description": "Capability for the main window",
+        "windows": ["main"],
+        "permissions": ["core:default"]
+    });
+    fs::write_file(
+        &tauri_path.join("capabilities/default.json"),
+        &serde_json::to_string_pretty(&capabilities)?,
+    )?;
+
+    fs::write_file(&tauri_path.join("icons/icon.png"), ICON_PNG)?;
+    fs::write_file(&tauri_path.join("icons/icon.ico"), png_to_ico(ICON_PNG))?;
+
+    let tauri_gitignore = r#"# Generated by Cargo
+/target/
+
+# Generated by Tauri
+/gen/schemas
+"#;
+    fs::write_file(&tauri_path.join(".gitignore"), tauri_gitignore)?;
+
+    // Generate README.md
+    let readme = format!(
+        "# {}\n\nA Tauri desktop app with a Vite + React frontend, generated with cp-cli.\n\n## Getting started\n\n```bash\n{}\n{}\n```\n\n## Project layout\n\n- `src/` - React frontend\n- `src-tauri/` - Rust backend; commands live in `src-tauri/src/main.rs`\n\n## Scripts\n\n- `{}` - start the desktop app with hot reload\n- `{}` - build installers for the current platform\n- `{}` - check the Rust backend\n- `{}` - generate the full icon set from a 1024x1024 PNG\n",
+        config.name,
+        pm.install_cmd(),
+        pm.run_cmd("tauri dev"),
+        pm.run_cmd("tauri dev"),
+        pm.run_cmd("tauri build"),
+        "cd src-tauri && cargo check",
+        pm.run_cmd("tauri icon path/to/icon.png"),
+    );
+    fs::write_file(&project_path.join("README.md"), &readme)?;
+
+    Ok(())
+}
+
+/// Wraps a PNG in a single-entry ICO container, which Windows builds require.
+fn png_to_ico(png: &[u8]) -> Vec<u8> {
+    let mut ico = vec![0, 0, 1, 0, 1, 0];
+    ico.extend_from_slice(&[32, 32, 0, 0]);
+    ico.extend_from_slice(&1u16.to_le_bytes());
+    ico.extend_from_slice(&32u16.to_le_bytes());
+    ico.extend_from_slice(&(png.len() as u32).to_le_bytes());
+    ico.extend_from_slice(&22u32.to_le_bytes());
+    ico.extend_from_slice(png);
+    ico
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn png_to_ico_writes_one_entry_pointing_at_the_png() {
+        let png = [0x89, b'P', b'N', b'G'];
+        let ico = png_to_ico(&png);
+        assert_eq!(ico.len(), 22 + png.len());
+        // Reserved, type 1 (icon), one image
+        assert_eq!(ico[..6], [0, 0, 1, 0, 1, 0]);
+        // 32x32, 32 bits per pixel
+        assert_eq!(ico[6..8], [32, 32]);
+        assert_eq!(u16::from_le_bytes([ico[12], ico[13]]), 32);
+        assert_eq!(
+            u32::from_le_bytes(ico[14..18].try_into().unwrap()),
+            png.len() as u32
+        );
+        assert_eq!(u32::from_le_bytes(ico[18..22].try_into().unwrap()), 22);
+        assert_eq!(ico[22..], png);
+    }
+}
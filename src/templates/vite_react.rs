@@ -0,0 +1,418 @@
+use crate::config::{Auth, ClientRouter, ProjectConfig};
+use crate::templates::{database, package_json};
+use crate::utils::fs;
+use anyhow::Result;
+use serde_json::json;
+use std::path::Path;
+
+pub fn generate_vite_react_project(config: &ProjectConfig, project_path: &Path) -> Result<()> {
+    let use_clerk = matches!(config.auth, Auth::Clerk);
+    // shadcn/ui components are styled with Tailwind, so it comes along
+    let use_tailwind = config.use_tailwind || config.use_shadcn;
+
+    // Generate package.json
+    let mut deps = serde_json::Map::new();
+    deps.insert("react".to_string(), json!("latest"));
+    deps.insert("react-dom".to_string(), json!("latest"));
+
+    match config.router {
+        ClientRouter::TanStackRouter => {
+            deps.insert("@tanstack/react-router".to_string(), json!("latest"));
+        }
+        ClientRouter::ReactRouter => {
+            deps.insert("react-router".to_string(), json!("latest"));
+        }
+        ClientRouter::None => {}
+    }
+
+    if use_tailwind {
+        deps.insert("tailwindcss".to_string(), json!("^3"));
+        deps.insert("postcss".to_string(), json!("latest"));
+        deps.insert("autoprefixer".to_string(), json!("latest"));
+    }
+
+    if config.use_shadcn {
+        deps.insert("class-variance-authority".to_string(), json!("latest"));
+        deps.insert("clsx".to_string(), json!("latest"));
+        deps.insert("tailwind-merge".to_string(), json!("latest"));
+        deps.insert("lucide-react".to_string(), json!("latest"));
+    }
+
+    if use_clerk {
+        deps.insert("@clerk/clerk-react".to_string(), json!("latest"));
+    }
+
+    database::add_dependencies(config, &mut deps);
+
+    let mut package_json = json!({
+        "name": config.name,
+        "version": "0.1.0",
+        "private": true,
+        "type": "module",
+        "scripts": {
+            "dev": "vite",
+            "build": "tsc -b && vite build",
+            "preview": "vite preview"
+        },
+        "dependencies": deps,
+        "devDependencies": {
+            "typescript": "latest",
+            "vite": "latest",
+            "@vitejs/plugin-react": "latest",
+            "@types/node": "latest",
+            "@types/react": "latest",
+            "@types/react-dom": "latest"
+        }
+    });
+
+    database::add_scripts(config, &mut package_json);
+
+    package_json::write_package_json(project_path, &package_json)?;
+
+    // Generate tsconfig.json
+    let tsconfig = json!({
+        "compilerOptions": {
+            "target": "ES2020",
+            "lib": ["dom", "dom.iterable", "esnext"],
+            "skipLibCheck": true,
+            "strict": true,
+            "noEmit": true,
+            "module": "esnext",
+            "moduleResolution": "bundler",
+            "resolveJsonModule": true,
+            "isolatedModules": true,
+            "jsx": "react-jsx",
+            "types": ["vite/client"],
+            "paths": {
+                "@/*": ["./src/*"]
+            }
+        },
+        "include": ["src", "vite.config.ts"]
+    });
+
+    fs::write_file(
+        &project_path.join("tsconfig.json"),
+        &serde_json::to_string_pretty(&tsconfig)?,
+    )?;
+
+    // Generate vite.config.ts
+    let vite_config = r#"import { defineConfig } from 'vite'
+import react from '@vitejs/plugin-react'
+import path from 'node:path'
+
+export default defineConfig({
+  plugins: [react()],
+  resolve: {
+    alias: {
+      '@': path.resolve(__dirname, './src'),
+    },
+  },
+})
+"#;
+    fs::write_file(&project_path.join("vite.config.ts"), vite_config)?;
+
+    // Generate index.html
+    let index_html = r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1.0" />
+    <title>{{PROJECT_NAME}}</title>
+  </head>
+  <body>
+    <div id="root"></div>
+    <script type="module" src="/src/main.tsx"></script>
+  </body>
+</html>
+"#;
+    let index_html = index_html.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(&project_path.join("index.html"), &index_html)?;
+
+    // Create src directory structure
+    fs::create_directory(&project_path.join("src"))?;
+
+    // Generate src/App.tsx
+    let app_content = r#"export default function App() {
+  return (
+    <main>
+      <h1>Welcome to {{PROJECT_NAME}}</h1>
+      <p>Get started by editing src/App.tsx</p>
+    </main>
+  )
+}
+"#;
+    let app_content = app_content.replace("{{PROJECT_NAME}}", &config.name);
+    fs::write_file(&project_path.join("src/App.tsx"), &app_content)?;
+
+    // Generate src/router.tsx for client-side routing
+    match config.router {
+        ClientRouter::TanStackRouter => {
+            let router_content = r#"import {
+  createRootRoute,
+  createRoute,
+  createRouter,
+  Outlet,
+} from '@tanstack/react-router'
+import App from './App'
+
+const rootRoute = createRootRoute({
+  component: () => <Outlet />,
+})
+
+const indexRoute = createRoute({
+  getParentRoute: () => rootRoute,
+  path: '/',
+  component: App,
+})
+
+const routeTree = rootRoute.addChildren([indexRoute])
+
+export const router = createRouter({ routeTree })
+
+declare module '@tanstack/react-router' {
+  interface Register {
+    router: typeof router
+  }
+}
+"#;
+            fs::write_file(&project_path.join("src/router.tsx"), router_content)?;
+        }
+        ClientRouter::ReactRouter => {
+            let router_content = r#"import { createBrowserRouter } from 'react-router'
+import App from './App'
+
+export const router = createBrowserRouter([
+  {
+    path: '/',
+    element: <App />,
+  },
+])
+"#;
+            fs::write_file(&project_path.join("src/router.tsx"), router_content)?;
+        }
+        ClientRouter::None => {}
+    }
+
+    // Generate src/main.tsx with the selected providers
+    let use_convex = matches!(config.database, crate::config::Database::Convex);
+
+    let mut imports = String::from(
+        "import { StrictMode } from 'react'\nimport { createRoot } from 'react-dom/client'\n",
+    );
+    let root_element = match config.router {
+        ClientRouter::TanStackRouter => {
+            imports.push_str("import { RouterProvider } from '@tanstack/react-router'\n");
+            imports.push_str("import { router } from './router'\n");
+            "<RouterProvider router={router} />"
+        }
+        ClientRouter::ReactRouter => {
+            imports.push_str("import { RouterProvider } from 'react-router'\n");
+            imports.push_str("import { router } from './router'\n");
+            "<RouterProvider router={router} />"
+        }
+        ClientRouter::None => {
+            imports.push_str("import App from './App'\n");
+            "<App />"
+        }
+    };
+    if use_clerk {
+        if use_convex {
+            imports.push_str("import { ClerkProvider, useAuth } from '@clerk/clerk-react'\n");
+        } else {
+            imports.push_str("import { ClerkProvider } from '@clerk/clerk-react'\n");
+        }
+    }
+    if use_convex && use_clerk {
+        imports.push_str("import { ConvexReactClient } from 'convex/react'\n");
+        imports.push_str("import { ConvexProviderWithClerk } from 'convex/react-clerk'\n");
+    } else if use_convex {
+        imports.push_str("import { ConvexProvider, ConvexReactClient } from 'convex/react'\n");
+    }
+    imports.push_str("import './index.css'\n");
+
+    let mut setup = String::new();
+    if use_clerk {
+        setup.push_str(
+            "const PUBLISHABLE_KEY = import.meta.env.VITE_CLERK_PUBLISHABLE_KEY\n\nif (!PUBLISHABLE_KEY) {\n  throw new Error('Missing VITE_CLERK_PUBLISHABLE_KEY')\n}\n\n",
+        );
+    }
+    if use_convex {
+        setup.push_str(
+            "const convex = new ConvexReactClient(import.meta.env.VITE_CONVEX_URL as string)\n\n",
+        );
+    }
+
+    // Wrap the root element in providers, outermost first
+    let mut wrappers = vec![("<StrictMode>", "</StrictMode>")];
+    if use_clerk {
+        wrappers.push((
+            "<ClerkProvider publishableKey={PUBLISHABLE_KEY}>",
+            "</ClerkProvider>",
+        ));
+    }
+    if use_convex && use_clerk {
+        // Convex authenticates with the Clerk session's token
+        wrappers.push((
+            "<ConvexProviderWithClerk client={convex} useAuth={useAuth}>",
+            "</ConvexProviderWithClerk>",
+        ));
+    } else if use_convex {
+        wrappers.push(("<ConvexProvider client={convex}>", "</ConvexProvider>"));
+    }
+
+    let mut tree = Vec::new();
+    for (i, (open, _)) in wrappers.iter().enumerate() {
+        tree.push(format!("{}{}", "  ".repeat(i + 1), open));
+    }
+    tree.push(format!(
+        "{}{}",
+        "  ".repeat(wrappers.len() + 1),
+        root_element
+    ));
+    for (i, (_, close)) in wrappers.iter().enumerate().rev() {
+        tree.push(format!("{}{}", "  ".repeat(i + 1), close));
+    }
+    let tree = tree.join("\n");
+
+    let main_content = format!(
+        "{}\n{}createRoot(document.getElementById('root')!).render(\n{},\n)\n",
+        imports, setup, tree
+    );
+    fs::write_file(&project_path.join("src/main.tsx"), &main_content)?;
+
+    // Generate src/index.css
+    let index_css = if use_tailwind {
+        r#"@tailwind base;
+@tailwind components;
+@tailwind utilities;
+"#
+    } else {
+        r#"* {
+  box-sizing: border-box;
+  padding: 0;
+  margin: 0;
+}
+"#
+    };
+    fs::write_file(&project_path.join("src/index.css"), index_css)?;
+
+    // Add Tailwind config if needed
+    if use_tailwind {
+        let tailwind_config = r#"/** @type {import('tailwindcss').Config} */
+export default {
+  content: ['./index.html', './src/**/*.{js,ts,jsx,tsx}'],
+  theme: {
+    extend: {},
+  },
+  plugins: [],
+}
+"#;
+        fs::write_file(&project_path.join("tailwind.config.js"), tailwind_config)?;
+
+        let postcss_config = r#"export default {
+  plugins: {
+    tailwindcss: {},
+    autoprefixer: {},
+  },
+}
+"#;
+        fs::write_file(&project_path.join("postcss.config.js"), postcss_config)?;
+    }
+
+    // Add shadcn/ui config if needed
+    if config.use_shadcn {
+        let components_json = json!({
+            "$schema": "https://ui.shadcn.com/schema.json",
+            "style": "new-york",
+            "rsc": false,
+            "tsx": true,
+            "tailwind": {
+                "config": "tailwind.config.js",
+                "css": "src/index.css",
+                "baseColor": "neutral",
+                "cssVariables": true
+            },
+            "aliases": {
+                "components": "@/components",
+                "utils": "@/lib/utils",
+                "ui": "@/components/ui",
+                "lib": "@/lib",
+                "hooks": "@/hooks"
+            }
+        });
+        fs::write_file(
+            &project_path.join("components.json"),
+            &serde_json::to_string_pretty(&components_json)?,
+        )?;
+
+        let utils = r#"import { clsx, type ClassValue } from 'clsx'
+import { twMerge } from 'tailwind-merge'
+
+export function cn(...inputs: ClassValue[]) {
+  return twMerge(clsx(inputs))
+}
+"#;
+        fs::write_file(&project_path.join("src/lib/utils.ts"), utils)?;
+    }
+
+    // Add database setup if needed
+    database::generate_database_files(config, project_path)?;
+
+    // Generate README.md
+    let pm = &config.package_manager;
+    let mut readme = format!(
+        "# {}\n\nA Vite + React single-page app generated with cp-cli.\n\n## Getting started\n\n```bash\n{}\n{}\n```\n\n## Scripts\n\n- `{}` - start the dev server\n- `{}` - type-check and build for production\n- `{}` - preview the production build\n",
+        config.name,
+        pm.install_cmd(),
+        pm.dev_cmd(),
+        pm.run_cmd("dev"),
+        pm.run_cmd("build"),
+        pm.run_cmd("preview"),
+    );
+    if config.use_shadcn {
+        readme.push_str(&format!(
+            "\n## UI components\n\nAdd shadcn/ui components with `{}`.\n",
+            pm.exec_cmd("shadcn@latest add button")
+        ));
+    }
+    fs::write_file(&project_path.join("README.md"), &readme)?;
+
+    // Generate .gitignore
+    let gitignore = r#"# dependencies
+/node_modules
+
+# build
+/dist
+
+# misc
+.DS_Store
+*.pem
+
+# debug
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+
+# local env files
+.env*.local
+
+# typescript
+*.tsbuildinfo
+"#;
+    let gitignore = format!("{}{}", gitignore, database::gitignore_entries(config));
+    fs::write_file(&project_path.join(".gitignore"), &gitignore)?;
+
+    // Generate .env.local if needed
+    if use_clerk || use_convex {
+        let mut env_content = String::new();
+        if use_clerk {
+            env_content.push_str("VITE_CLERK_PUBLISHABLE_KEY=\n");
+        }
+        if use_convex {
+            env_content.push_str("VITE_CONVEX_URL=\n");
+        }
+        fs::write_file(&project_path.join(".env.local"), &env_content)?;
+    }
+
+    Ok(())
+}
@@ -16,7 +16,7 @@ pub fn create_project_directory(name: &str) -> Result<PathBuf> {
     Ok(project_path)
 }
 
-pub fn write_file(path: &Path, content: &str) -> Result<()> {
+pub fn write_file(path: &Path, content: impl AsRef<[u8]>) -> Result<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)
             .with_context(|| format!("Failed to create parent directory: {}", parent.display()))?;
@@ -1,3 +1,6 @@
 pub mod fs;
 pub mod git;
+pub mod name;
 pub mod package_manager;
+pub mod secret;
+pub mod workspace;
@@ -0,0 +1,41 @@
+/// Lowercases `name` and joins its runs of ASCII letters, digits and `_`
+/// with single hyphens, so `@acme/My App` becomes `acme-my-app`. A leading
+/// digit gets an `app-` prefix. The result is a valid Cargo package name and
+/// Expo slug.
+pub fn slug(name: &str) -> String {
+    let slug = name
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    match slug.chars().next() {
+        None => "app".to_string(),
+        Some(c) if c.is_ascii_digit() => format!("app-{}", slug),
+        Some(_) => slug,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slug_replaces_invalid_characters() {
+        assert_eq!(slug("My App"), "my-app");
+        assert_eq!(slug("my_app.v2"), "my_app-v2");
+    }
+
+    #[test]
+    fn slug_trims_and_collapses_separators() {
+        assert_eq!(slug("@acme/app"), "acme-app");
+        assert_eq!(slug("  my -- app!"), "my-app");
+    }
+
+    #[test]
+    fn slug_prefixes_a_leading_digit() {
+        assert_eq!(slug("1app"), "app-1app");
+        assert_eq!(slug("2d/"), "app-2d");
+        assert_eq!(slug("@/"), "app");
+    }
+}
@@ -1,7 +1,5 @@
 use crate::config::PackageManager;
-use anyhow::Result;
 use std::fs;
-use std::path::Path;
 use std::process::Command;
 
 impl PackageManager {
@@ -82,6 +80,37 @@ impl PackageManager {
             PackageManager::Bun => format!("bun {}", command),
         }
     }
+
+    /// Version specifier for depending on a sibling workspace package. npm
+    /// has no `workspace:` protocol and links any matching local package.
+    pub fn workspace_version(&self) -> &str {
+        match self {
+            PackageManager::Npm => "*",
+            PackageManager::Pnpm | PackageManager::Yarn | PackageManager::Bun => "workspace:*",
+        }
+    }
+
+    /// Value for the root `packageManager` field, which Turborepo requires.
+    /// Falls back to a known release when the binary isn't installed.
+    pub fn package_manager_field(&self) -> String {
+        let (binary, fallback) = match self {
+            PackageManager::Npm => ("npm", "10.9.0"),
+            PackageManager::Pnpm => ("pnpm", "9.15.0"),
+            PackageManager::Yarn => ("yarn", "4.5.3"),
+            PackageManager::Bun => ("bun", "1.1.38"),
+        };
+
+        let version = Command::new(binary)
+            .arg("--version")
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+            .filter(|v| !v.is_empty())
+            .unwrap_or_else(|| fallback.to_string());
+
+        format!("{}@{}", binary, version)
+    }
 }
 
 fn command_exists(command: &str) -> bool {
@@ -0,0 +1,9 @@
+use anyhow::Result;
+
+/// A random 32-byte secret, hex encoded, for signing sessions locally.
+pub fn generate_secret() -> Result<String> {
+    let mut bytes = [0u8; 32];
+    getrandom::fill(&mut bytes)
+        .map_err(|err| anyhow::anyhow!("Failed to generate a secret: {}", err))?;
+    Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
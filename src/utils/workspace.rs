@@ -0,0 +1,307 @@
+use crate::config::PackageManager;
+use crate::utils::fs as fs_utils;
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A JavaScript workspace (pnpm, npm, yarn or bun) that new apps can join.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    pub root: PathBuf,
+    pub package_manager: PackageManager,
+    pub patterns: Vec<String>,
+}
+
+impl Workspace {
+    /// Walks up from `start` looking for a workspace root.
+    pub fn detect(start: &Path) -> Option<Self> {
+        start.ancestors().find_map(Self::at)
+    }
+
+    fn at(dir: &Path) -> Option<Self> {
+        let pnpm_workspace = dir.join("pnpm-workspace.yaml");
+        if pnpm_workspace.exists() {
+            let content = fs::read_to_string(&pnpm_workspace).ok()?;
+            return Some(Self {
+                root: dir.to_path_buf(),
+                package_manager: PackageManager::Pnpm,
+                patterns: parse_pnpm_patterns(&content),
+            });
+        }
+
+        let package_json: Value =
+            serde_json::from_str(&fs::read_to_string(dir.join("package.json")).ok()?).ok()?;
+        let workspaces = &package_json["workspaces"];
+        let patterns = workspaces
+            .as_array()
+            .or_else(|| workspaces["packages"].as_array())?
+            .iter()
+            .filter_map(|p| p.as_str().map(String::from))
+            .collect();
+
+        Some(Self {
+            root: dir.to_path_buf(),
+            package_manager: detect_package_manager(dir, &package_json),
+            patterns,
+        })
+    }
+
+    /// Whether apps created from `dir` belong in the workspace: `dir` is the
+    /// root itself, or a directory whose children the globs cover, like
+    /// `apps/`.
+    pub fn contains_apps_in(&self, dir: &Path) -> bool {
+        match dir.strip_prefix(&self.root) {
+            Ok(relative) => relative.as_os_str().is_empty() || self.includes(&relative.join("*")),
+            Err(_) => false,
+        }
+    }
+
+    /// Whether a directory (relative to the root) is already covered by the
+    /// workspace globs.
+    pub fn includes(&self, relative: &Path) -> bool {
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        self.patterns.iter().any(|pattern| {
+            let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+            if let Some(prefix) = pattern.strip_suffix("/**") {
+                relative.starts_with(&format!("{}/", prefix))
+            } else if let Some(prefix) = pattern.strip_suffix("/*") {
+                Path::new(&relative).parent() == Some(Path::new(prefix))
+            } else {
+                pattern == relative
+            }
+        })
+    }
+
+    /// Adds a directory (relative to the root) to the workspace globs.
+    pub fn register(&self, relative: &Path) -> Result<()> {
+        let relative = relative.to_string_lossy().replace('\\', "/");
+
+        if matches!(self.package_manager, PackageManager::Pnpm) {
+            let path = self.root.join("pnpm-workspace.yaml");
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read file: {}", path.display()))?;
+            let entry = format!("  - \"{}\"", relative);
+            let mut lines: Vec<&str> = content.lines().collect();
+            match pnpm_package_lines(&lines) {
+                // After the last item, so trailing comments and blank lines stay put
+                Some((start, items)) => {
+                    let at = items.last().map_or(start, |&i| i) + 1;
+                    lines.insert(at, &entry);
+                }
+                None => {
+                    lines.push("packages:");
+                    lines.push(&entry);
+                }
+            }
+            return fs_utils::write_file(&path, format!("{}\n", lines.join("\n")));
+        }
+
+        let path = self.root.join("package.json");
+        let mut package_json: Value = serde_json::from_str(
+            &fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read file: {}", path.display()))?,
+        )
+        .with_context(|| format!("Failed to parse file: {}", path.display()))?;
+        let workspaces = if package_json["workspaces"].is_array() {
+            &mut package_json["workspaces"]
+        } else {
+            &mut package_json["workspaces"]["packages"]
+        };
+        match workspaces.as_array_mut() {
+            Some(patterns) => patterns.push(json!(relative)),
+            None => *workspaces = json!([relative]),
+        }
+        fs_utils::write_file(&path, &serde_json::to_string_pretty(&package_json)?)
+    }
+}
+
+/// Indices of the `packages:` line in pnpm-workspace.yaml and of its `- `
+/// items. Blank and comment lines inside the list don't end it.
+fn pnpm_package_lines(lines: &[&str]) -> Option<(usize, Vec<usize>)> {
+    let start = lines.iter().position(|l| l.trim_end() == "packages:")?;
+    let items = lines[start + 1..]
+        .iter()
+        .map(|l| l.trim_start())
+        .take_while(|l| l.is_empty() || l.starts_with('-') || l.starts_with('#'))
+        .enumerate()
+        .filter(|(_, l)| l.starts_with('-'))
+        .map(|(i, _)| start + 1 + i)
+        .collect();
+    Some((start, items))
+}
+
+fn parse_pnpm_patterns(content: &str) -> Vec<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let Some((_, items)) = pnpm_package_lines(&lines) else {
+        return Vec::new();
+    };
+    items
+        .into_iter()
+        .filter_map(|i| {
+            let item = lines[i].trim_start().strip_prefix('-')?;
+            // Drop a trailing `# comment`
+            let item = item.split(" #").next().unwrap_or(item).trim();
+            let item = item.trim_matches(|c| c == '"' || c == '\'');
+            (!item.is_empty() && !item.starts_with('!')).then(|| item.to_string())
+        })
+        .collect()
+}
+
+fn detect_package_manager(root: &Path, package_json: &Value) -> PackageManager {
+    // 1. The `packageManager` field (Corepack, Turborepo)
+    if let Some(field) = package_json["packageManager"].as_str() {
+        match field.split('@').next() {
+            Some("pnpm") => return PackageManager::Pnpm,
+            Some("yarn") => return PackageManager::Yarn,
+            Some("bun") => return PackageManager::Bun,
+            Some("npm") => return PackageManager::Npm,
+            _ => {}
+        }
+    }
+
+    // 2. Lock files at the workspace root
+    for (lockfile, pm) in [
+        ("bun.lockb", PackageManager::Bun),
+        ("bun.lock", PackageManager::Bun),
+        ("pnpm-lock.yaml", PackageManager::Pnpm),
+        ("yarn.lock", PackageManager::Yarn),
+    ] {
+        if root.join(lockfile).exists() {
+            return pm;
+        }
+    }
+
+    // 3. Default to npm, the only other manager reading `workspaces`
+    PackageManager::Npm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::fixtures::temp_root;
+
+    fn workspace(root: PathBuf, package_manager: PackageManager, patterns: &[&str]) -> Workspace {
+        Workspace {
+            root,
+            package_manager,
+            patterns: patterns.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn parse_pnpm_patterns_reads_the_packages_list() {
+        let content = "packages:\n  - \"apps/*\"\n  - 'packages/*'\n  - tools/cli\n  - \"!**/test/**\"\n\ncatalog:\n  - react\n";
+        assert_eq!(
+            parse_pnpm_patterns(content),
+            ["apps/*", "packages/*", "tools/cli"]
+        );
+    }
+
+    #[test]
+    fn parse_pnpm_patterns_skips_comments() {
+        let content = "packages:\n  # Apps\n  - \"apps/*\"\n  # Shared code\n  - \"packages/*\" # libraries\n";
+        assert_eq!(parse_pnpm_patterns(content), ["apps/*", "packages/*"]);
+    }
+
+    #[test]
+    fn parse_pnpm_patterns_without_packages() {
+        assert!(parse_pnpm_patterns("catalog:\n  react: ^19\n").is_empty());
+    }
+
+    #[test]
+    fn includes_matches_globs() {
+        let workspace = workspace(
+            PathBuf::from("/repo"),
+            PackageManager::Pnpm,
+            &["./apps/*", "packages/**", "tools/cli"],
+        );
+        assert!(workspace.includes(Path::new("apps/web")));
+        assert!(!workspace.includes(Path::new("apps/web/nested")));
+        assert!(workspace.includes(Path::new("packages/ui/react")));
+        assert!(workspace.includes(Path::new("tools/cli")));
+        assert!(!workspace.includes(Path::new("tools/other")));
+        assert!(!workspace.includes(Path::new("examples/web")));
+    }
+
+    #[test]
+    fn contains_apps_in_root_and_glob_parents() {
+        let workspace = workspace(PathBuf::from("/repo"), PackageManager::Npm, &["apps/*"]);
+        assert!(workspace.contains_apps_in(Path::new("/repo")));
+        assert!(workspace.contains_apps_in(Path::new("/repo/apps")));
+        assert!(!workspace.contains_apps_in(Path::new("/repo/scratch")));
+        assert!(!workspace.contains_apps_in(Path::new("/elsewhere")));
+    }
+
+    #[test]
+    fn register_appends_a_pnpm_item() {
+        let root = temp_root("register-pnpm");
+        fs::write(
+            root.join("pnpm-workspace.yaml"),
+            "packages:\n  - \"apps/*\"\n",
+        )
+        .unwrap();
+
+        let workspace = workspace(root.clone(), PackageManager::Pnpm, &["apps/*"]);
+        workspace.register(Path::new("tools/admin")).unwrap();
+
+        let written = fs::read_to_string(root.join("pnpm-workspace.yaml")).unwrap();
+        assert_eq!(parse_pnpm_patterns(&written), ["apps/*", "tools/admin"]);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn register_appends_after_the_last_pnpm_item() {
+        let root = temp_root("register-pnpm");
+        let content = "packages:\n  # Apps\n  - \"apps/*\"\n  # Shared code\n  - \"packages/*\"\n\n# Versions\ncatalog:\n  react: ^19\n";
+        fs::write(root.join("pnpm-workspace.yaml"), content).unwrap();
+
+        let workspace = workspace(root.clone(), PackageManager::Pnpm, &[]);
+        workspace.register(Path::new("tools/admin")).unwrap();
+
+        let written = fs::read_to_string(root.join("pnpm-workspace.yaml")).unwrap();
+        assert_eq!(
+            written,
+            "packages:\n  # Apps\n  - \"apps/*\"\n  # Shared code\n  - \"packages/*\"\n  - \"tools/admin\"\n\n# Versions\ncatalog:\n  react: ^19\n"
+        );
+        assert_eq!(
+            parse_pnpm_patterns(&written),
+            ["apps/*", "packages/*", "tools/admin"]
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn register_adds_a_pnpm_packages_list() {
+        let root = temp_root("register-pnpm-empty");
+        fs::write(root.join("pnpm-workspace.yaml"), "catalog:\n  react: ^19\n").unwrap();
+
+        let workspace = workspace(root.clone(), PackageManager::Pnpm, &[]);
+        workspace.register(Path::new("apps/admin")).unwrap();
+
+        let written = fs::read_to_string(root.join("pnpm-workspace.yaml")).unwrap();
+        assert_eq!(parse_pnpm_patterns(&written), ["apps/admin"]);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn register_appends_to_package_json_workspaces() {
+        let root = temp_root("register-npm");
+        fs::write(
+            root.join("package.json"),
+            r#"{ "name": "repo", "workspaces": { "packages": ["apps/*"] } }"#,
+        )
+        .unwrap();
+
+        let workspace = workspace(root.clone(), PackageManager::Yarn, &["apps/*"]);
+        workspace.register(Path::new("tools/admin")).unwrap();
+
+        let package_json: Value =
+            serde_json::from_str(&fs::read_to_string(root.join("package.json")).unwrap()).unwrap();
+        assert_eq!(
+            package_json["workspaces"]["packages"],
+            json!(["apps/*", "tools/admin"])
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+}